@@ -0,0 +1,23 @@
+use super::aligned;
+use crate::BigDecimal;
+
+/// Exact, with the larger scale of the operands
+impl std::ops::Add for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (x, y, scale) = aligned(self, rhs);
+        BigDecimal {
+            unscaled: &x + &y,
+            scale,
+        }
+    }
+}
+
+impl std::ops::Add for BigDecimal {
+    type Output = BigDecimal;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        (&self).add(&rhs)
+    }
+}
@@ -0,0 +1,45 @@
+use super::aligned;
+use crate::{APNum, BigDecimal};
+
+/// By value, 1.0 equals 1.00
+impl std::cmp::PartialEq for BigDecimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl std::cmp::Eq for BigDecimal {}
+
+impl std::cmp::Ord for BigDecimal {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self.unscaled.sign != other.unscaled.sign || self.scale == other.scale {
+            return self.unscaled.cmp(&other.unscaled);
+        }
+        if self.is_zero() {
+            return std::cmp::Ordering::Equal;
+        }
+
+        // 10^(magnitude - 1) ≤ |value| < 10^magnitude decides unless both are the same, the scales
+        // differ by the difference of the digit counts at most then
+        let magnitude =
+            |x: &BigDecimal| x.unscaled.natural.decimal_digit_count() as i128 - x.scale as i128;
+        let ordering = match magnitude(self).cmp(&magnitude(other)) {
+            std::cmp::Ordering::Equal => {
+                let (x, y, _) = aligned(self, other);
+                return x.cmp(&y);
+            }
+            ordering => ordering,
+        };
+        if self.is_negative() {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+impl std::cmp::PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,46 @@
+use super::power_of_ten;
+use crate::rounding::div_rounded;
+use crate::{APNum, BigDecimal, BigInt, RoundingMode, Sign};
+
+impl BigDecimal {
+    /// self / rhs with the given scale, rounded by the mode
+    pub fn div_rounded(&self, rhs: &BigDecimal, scale: i64, mode: RoundingMode) -> BigDecimal {
+        if rhs.is_zero() {
+            panic!("Division by Zero");
+        }
+
+        // self / rhs * 10^scale = x / y * 10^(scale - self.scale + rhs.scale)
+        let shift = scale
+            .checked_sub(self.scale)
+            .and_then(|shift| shift.checked_add(rhs.scale))
+            .expect("Scale out of range");
+        let power = power_of_ten(shift.unsigned_abs());
+        let (numerator, denominator) = if shift >= 0 {
+            (
+                &self.unscaled.natural * &power,
+                rhs.unscaled.natural.clone(),
+            )
+        } else {
+            (
+                self.unscaled.natural.clone(),
+                &rhs.unscaled.natural * &power,
+            )
+        };
+
+        let negative = self.is_negative() != rhs.is_negative();
+        let sign = if negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+
+        BigDecimal {
+            unscaled: BigInt {
+                sign,
+                natural: div_rounded(&numerator, &denominator, negative, mode),
+            }
+            .zero_normalized(),
+            scale,
+        }
+    }
+}
@@ -0,0 +1,264 @@
+pub mod add;
+pub mod cmp;
+pub mod div;
+pub mod mul;
+pub mod sub;
+
+use crate::bigfloat::parse_scientific;
+use crate::rounding::div_rounded;
+use crate::{APNum, APNumParseError, BigDecimal, BigInt, BigNat, RoundingMode};
+
+impl BigDecimal {
+    /// unscaled * 10^-scale
+    pub fn new(unscaled: BigInt, scale: i64) -> Self {
+        BigDecimal { unscaled, scale }
+    }
+
+    pub fn unscaled(&self) -> &BigInt {
+        &self.unscaled
+    }
+
+    pub fn scale(&self) -> i64 {
+        self.scale
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.unscaled.is_negative()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.unscaled.is_positive()
+    }
+
+    pub fn abs(&self) -> Self {
+        BigDecimal {
+            unscaled: self.unscaled.abs(),
+            scale: self.scale,
+        }
+    }
+
+    /// self with the given scale, rounded by the mode when digits are dropped
+    pub fn rescale(&self, scale: i64, mode: RoundingMode) -> Self {
+        if scale >= self.scale {
+            return BigDecimal {
+                unscaled: upscaled(&self.unscaled, scale.abs_diff(self.scale)),
+                scale,
+            };
+        }
+
+        let power = power_of_ten(self.scale.abs_diff(scale));
+        let natural = div_rounded(&self.unscaled.natural, &power, self.is_negative(), mode);
+        BigDecimal {
+            unscaled: BigInt {
+                sign: self.unscaled.sign.clone(),
+                natural,
+            }
+            .zero_normalized(),
+            scale,
+        }
+    }
+
+    /// Same value with the trailing zeros of the unscaled value removed (scale 0 for zero)
+    pub fn normalize(&self) -> Self {
+        if self.is_zero() {
+            return BigDecimal::zero();
+        }
+
+        let mut natural = self.unscaled.natural.clone();
+        let mut scale = self.scale;
+        loop {
+            let (quotient, remainder) = &natural / 10u32;
+            if remainder != 0 {
+                break;
+            }
+
+            natural = quotient;
+            scale -= 1;
+        }
+
+        BigDecimal {
+            unscaled: BigInt {
+                sign: self.unscaled.sign.clone(),
+                natural,
+            },
+            scale,
+        }
+    }
+}
+
+/// 10^n, panics for n past 32 bits
+fn power_of_ten(n: u64) -> BigNat {
+    let n = u32::try_from(n).expect("Scale out of range");
+    BigNat::from(10usize).pow(n)
+}
+
+/// x * 10^n
+fn upscaled(x: &BigInt, n: u64) -> BigInt {
+    if n == 0 || x.is_zero() {
+        return x.clone();
+    }
+
+    BigInt {
+        sign: x.sign.clone(),
+        natural: &x.natural * &power_of_ten(n),
+    }
+    .zero_normalized()
+}
+
+/// Unscaled values of x and y at the larger scale of the two, and that scale
+fn aligned(x: &BigDecimal, y: &BigDecimal) -> (BigInt, BigInt, i64) {
+    let scale = x.scale.max(y.scale);
+    (
+        upscaled(&x.unscaled, scale.abs_diff(x.scale)),
+        upscaled(&y.unscaled, scale.abs_diff(y.scale)),
+        scale,
+    )
+}
+
+impl APNum for BigDecimal {
+    fn zero() -> Self {
+        BigDecimal {
+            unscaled: BigInt::zero(),
+            scale: 0,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.unscaled.is_zero()
+    }
+
+    fn zero_normalized(self) -> Self {
+        self
+    }
+
+    fn digit_count(&self) -> usize {
+        self.unscaled.digit_count()
+    }
+}
+
+impl std::ops::Neg for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn neg(self) -> Self::Output {
+        BigDecimal {
+            unscaled: -&self.unscaled,
+            scale: self.scale,
+        }
+    }
+}
+
+impl std::ops::Neg for BigDecimal {
+    type Output = BigDecimal;
+
+    fn neg(self) -> Self::Output {
+        -(&self)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty)*) => ($(
+        impl From<$t> for BigDecimal {
+            fn from(value: $t) -> Self {
+                BigDecimal::from(BigInt::from(value))
+            }
+        }
+    )*)
+}
+
+impl_from_integer!(usize u8 u16 u32 u64 isize i8 i16 i32 i64);
+
+impl From<BigInt> for BigDecimal {
+    fn from(value: BigInt) -> Self {
+        BigDecimal {
+            unscaled: value,
+            scale: 0,
+        }
+    }
+}
+
+impl std::str::FromStr for BigDecimal {
+    type Err = APNumParseError;
+
+    /// Keeps the digits as given, "-123.4500e-7" is -1234500 with scale 11
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (unscaled, exponent) = parse_scientific(s)?;
+        let scale = exponent.checked_neg().ok_or(APNumParseError::Invalid)?;
+        Ok(BigDecimal { unscaled, scale })
+    }
+}
+
+impl TryFrom<&str> for BigDecimal {
+    type Error = APNumParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Parses back to the same unscaled value and scale. Plain notation unless the scale is negative
+/// or the value is below 10^-6, then scientific notation with one digit before the point.
+impl std::fmt::Display for BigDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+
+        let digits = self.unscaled.natural.to_string();
+        let adjusted = digits.len() as i64 - 1 - self.scale;
+
+        if self.scale < 0 || adjusted < -6 {
+            return match digits.split_at(1) {
+                (first, "") => write!(f, "{first}e{adjusted}"),
+                (first, rest) => write!(f, "{first}.{rest}e{adjusted}"),
+            };
+        }
+
+        let scale = self.scale as usize;
+        if scale == 0 {
+            write!(f, "{digits}")
+        } else if digits.len() > scale {
+            let (integer, fraction) = digits.split_at(digits.len() - scale);
+            write!(f, "{integer}.{fraction}")
+        } else {
+            let zeros = "0".repeat(scale - digits.len());
+            write!(f, "0.{zeros}{digits}")
+        }
+    }
+}
+
+impl std::fmt::Debug for BigDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BigDecimal, Sign};
+
+    #[test]
+    fn scientific() {
+        let result = "-123.4500e-7".parse::<BigDecimal>();
+        assert!(result.is_ok_and(|decimal| matches!(
+            decimal.unscaled.natural.digits[..],
+            [1234500]
+        ) && decimal.unscaled.sign == Sign::Negative
+            && decimal.scale == 11))
+    }
+
+    #[test]
+    fn negative_scale() {
+        let result = "12e3".parse::<BigDecimal>();
+        assert!(result.is_ok_and(
+            |decimal| matches!(decimal.unscaled.natural.digits[..], [12]) && decimal.scale == -3
+        ))
+    }
+
+    #[test]
+    fn trailing_zeros() {
+        let result = "0.000".parse::<BigDecimal>();
+        assert!(
+            result.is_ok_and(|decimal| decimal.unscaled.sign == Sign::Zero && decimal.scale == 3)
+        )
+    }
+}
@@ -0,0 +1,24 @@
+use crate::BigDecimal;
+
+/// Exact, with the sum of the scales of the operands
+impl std::ops::Mul for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        BigDecimal {
+            unscaled: &self.unscaled * &rhs.unscaled,
+            scale: self
+                .scale
+                .checked_add(rhs.scale)
+                .expect("Scale out of range"),
+        }
+    }
+}
+
+impl std::ops::Mul for BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        (&self).mul(&rhs)
+    }
+}
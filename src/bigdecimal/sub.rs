@@ -0,0 +1,23 @@
+use super::aligned;
+use crate::BigDecimal;
+
+/// Exact, with the larger scale of the operands
+impl std::ops::Sub for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (x, y, scale) = aligned(self, rhs);
+        BigDecimal {
+            unscaled: &x - &y,
+            scale,
+        }
+    }
+}
+
+impl std::ops::Sub for BigDecimal {
+    type Output = BigDecimal;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        (&self).sub(&rhs)
+    }
+}
@@ -0,0 +1,76 @@
+use super::{round, top};
+use crate::{APNum, BigFloat, BigNat, RoundingMode};
+
+impl BigFloat {
+    /// self + rhs rounded to precision bits
+    pub fn add_rounded(&self, rhs: &BigFloat, precision: usize, mode: RoundingMode) -> BigFloat {
+        add(self, rhs, false, precision, mode)
+    }
+}
+
+/// x + y, or x - y if negate, rounded to precision bits
+pub(crate) fn add(
+    x: &BigFloat,
+    y: &BigFloat,
+    negate: bool,
+    precision: usize,
+    mode: RoundingMode,
+) -> BigFloat {
+    let x_negative = x.is_negative();
+    let y_negative = y.is_negative() != negate;
+    let x = (x_negative, &x.mantissa.natural, x.exponent, top(x));
+    let y = (y_negative, &y.mantissa.natural, y.exponent, top(y));
+    if y.1.is_zero() {
+        return round(x.0, x.1, x.2, false, precision, mode);
+    }
+    if x.1.is_zero() {
+        return round(y.0, y.1, y.2, false, precision, mode);
+    }
+
+    // a is the one with the higher top
+    let ((a_negative, a, a_exponent, a_top), (b_negative, b, b_exponent, b_top)) =
+        if x.3 >= y.3 { (x, y) } else { (y, x) };
+
+    // When b lies entirely below both the last bit of a and two bits below the rounding position,
+    // only its sign matters, so it is replaced by a small number of the same sign. This keeps the
+    // exact sum short for far apart exponents.
+    let cutoff = a_exponent.min(a_top - precision as i64 - 2);
+    let small = BigNat::from(1usize);
+    let (b, b_exponent) = if b_top < cutoff {
+        (&small, cutoff - 1)
+    } else {
+        (b, b_exponent)
+    };
+
+    let exponent = a_exponent.min(b_exponent);
+    let a = a << (a_exponent - exponent) as usize;
+    let b = b << (b_exponent - exponent) as usize;
+
+    let (negative, magnitude) = if a_negative == b_negative {
+        (a_negative, &a + &b)
+    } else if a >= b {
+        (a_negative, (&a - &b).natural)
+    } else {
+        (b_negative, (&b - &a).natural)
+    };
+
+    round(negative, &magnitude, exponent, false, precision, mode)
+}
+
+/// Rounded to nearest with the larger precision of the operands
+impl std::ops::Add for &BigFloat {
+    type Output = BigFloat;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let precision = self.precision.max(rhs.precision);
+        self.add_rounded(rhs, precision, RoundingMode::HalfEven)
+    }
+}
+
+impl std::ops::Add for BigFloat {
+    type Output = BigFloat;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        (&self).add(&rhs)
+    }
+}
@@ -0,0 +1,276 @@
+// Ball arithmetic on fixed point numbers, the error bounds of the transcendental functions.
+// A ball is center * 2^-scale with an error of at most 2^radius units of 2^-scale.
+
+use super::ziv::Approximation;
+use crate::{APNum, BigFloat, BigInt, Sign};
+
+#[derive(Clone)]
+pub(crate) struct Ball {
+    pub(crate) center: BigInt,
+    /// log2 of the error bound in units, -inf for an exact value, +inf for an unbounded one
+    pub(crate) radius: f64,
+    pub(crate) scale: usize,
+}
+
+/// Added to the computed bounds against the rounding errors of f64
+const SLACK: f64 = 1e-9;
+
+/// log2(2^a + 2^b)
+pub(crate) fn log_add(a: f64, b: f64) -> f64 {
+    let (high, low) = if a >= b { (a, b) } else { (b, a) };
+    if low == f64::NEG_INFINITY || high == f64::INFINITY {
+        return high;
+    }
+    high + (1.0 + (low - high).exp2()).log2() + SLACK
+}
+
+/// x / 2^shift truncated toward zero
+pub(crate) fn shr_int(x: &BigInt, shift: usize) -> BigInt {
+    BigInt {
+        sign: x.sign.clone(),
+        natural: &x.natural >> shift,
+    }
+    .zero_normalized()
+}
+
+pub(crate) fn shl_int(x: &BigInt, shift: usize) -> BigInt {
+    BigInt {
+        sign: x.sign.clone(),
+        natural: &x.natural << shift,
+    }
+}
+
+/// x / y truncated toward zero
+pub(crate) fn div_int(x: &BigInt, y: &BigInt) -> BigInt {
+    let (quotient, _) = &x.natural / &y.natural;
+    let sign = if x.is_negative() != y.is_negative() {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+
+    BigInt {
+        sign,
+        natural: quotient,
+    }
+    .zero_normalized()
+}
+
+impl Ball {
+    pub(crate) fn exact(center: BigInt, scale: usize) -> Ball {
+        Ball {
+            center,
+            radius: f64::NEG_INFINITY,
+            scale,
+        }
+    }
+
+    fn unbounded(scale: usize) -> Ball {
+        Ball {
+            center: BigInt::zero(),
+            radius: f64::INFINITY,
+            scale,
+        }
+    }
+
+    pub(crate) fn one(scale: usize) -> Ball {
+        Ball::exact(shl_int(&BigInt::from(1), scale), scale)
+    }
+
+    /// x truncated to the scale
+    pub(crate) fn from_float(x: &BigFloat, scale: usize) -> Ball {
+        let shift = x.exponent + scale as i64;
+        if shift >= 0 {
+            Ball::exact(shl_int(&x.mantissa, shift as usize), scale)
+        } else {
+            Ball {
+                center: shr_int(&x.mantissa, shift.unsigned_abs() as usize),
+                radius: 0.0,
+                scale,
+            }
+        }
+    }
+
+    /// Approximation of center / 2^scale to f64
+    pub(crate) fn to_f64(&self) -> f64 {
+        let bits = self.center.natural.bits();
+        let shift = bits.saturating_sub(64);
+        let top = shr_int(&self.center, shift);
+        let magnitude = top
+            .natural
+            .digits
+            .iter()
+            .rev()
+            .fold(0.0, |acc, digit| acc * 4294967296.0 + *digit as f64);
+        let value = magnitude * (shift as f64 - self.scale as f64).exp2();
+        if top.is_negative() {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// log2 of an upper bound of |center| in units
+    fn high(&self) -> f64 {
+        self.center.natural.bits() as f64
+    }
+
+    /// log2 of a lower bound of |center| in units
+    fn low(&self) -> f64 {
+        match self.center.natural.bits() {
+            0 => f64::NEG_INFINITY,
+            bits => (bits - 1) as f64,
+        }
+    }
+
+    /// log2 of a lower bound of |value| in units, -inf when the ball contains zero
+    fn lower(&self) -> f64 {
+        let low = self.low();
+        if self.radius >= low {
+            return f64::NEG_INFINITY;
+        }
+        low + (1.0 - (self.radius - low).exp2()).log2() - SLACK
+    }
+
+    /// log2 of an upper bound of |value| (not in units)
+    pub(crate) fn upper(&self) -> f64 {
+        log_add(self.high(), self.radius) - self.scale as f64
+    }
+
+    pub(crate) fn add(&self, rhs: &Ball) -> Ball {
+        debug_assert!(self.scale == rhs.scale);
+        Ball {
+            center: &self.center + &rhs.center,
+            radius: log_add(self.radius, rhs.radius),
+            scale: self.scale,
+        }
+    }
+
+    pub(crate) fn sub(&self, rhs: &Ball) -> Ball {
+        self.add(&rhs.neg())
+    }
+
+    pub(crate) fn neg(&self) -> Ball {
+        Ball {
+            center: -&self.center,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn mul(&self, rhs: &Ball) -> Ball {
+        debug_assert!(self.scale == rhs.scale);
+        if self.radius == f64::INFINITY || rhs.radius == f64::INFINITY {
+            return Ball::unbounded(self.scale);
+        }
+        let scale = self.scale as f64;
+
+        // (x + e)(y + f) - xy = xf + ye + ef, and a unit for the truncation
+        let radius = log_add(
+            log_add(self.high() + rhs.radius, rhs.high() + self.radius),
+            self.radius + rhs.radius,
+        ) - scale;
+
+        Ball {
+            center: shr_int(&(&self.center * &rhs.center), self.scale),
+            radius: log_add(radius, 0.0),
+            scale: self.scale,
+        }
+    }
+
+    pub(crate) fn div(&self, rhs: &Ball) -> Ball {
+        debug_assert!(self.scale == rhs.scale);
+        let lower = rhs.lower();
+        if lower == f64::NEG_INFINITY || self.radius == f64::INFINITY {
+            return Ball::unbounded(self.scale);
+        }
+
+        // (x + e) / (y + f) - x / y = (ey - xf) / (y (y + f)), and a unit for the truncation
+        let scale = self.scale as f64;
+        let radius = log_add(
+            scale + self.radius - lower,
+            scale + self.high() - rhs.low() + rhs.radius - lower,
+        );
+
+        Ball {
+            center: div_int(&shl_int(&self.center, self.scale), &rhs.center),
+            radius: log_add(radius, 0.0),
+            scale: self.scale,
+        }
+    }
+
+    pub(crate) fn sqrt(&self) -> Ball {
+        let lower = self.lower();
+        if self.center.is_negative() || lower == f64::NEG_INFINITY {
+            return Ball::unbounded(self.scale);
+        }
+
+        // |sqrt(x + e) - sqrt(x)| ≤ |e| / (2 sqrt(x - |e|)), and a unit for the truncation
+        let radius = self.radius + self.scale as f64 / 2.0 - 1.0 - lower / 2.0;
+        let (root, _) = (&self.center.natural << self.scale).sqrt_rem();
+
+        Ball {
+            center: BigInt::from(root),
+            radius: log_add(radius, 0.0),
+            scale: self.scale,
+        }
+    }
+
+    /// self * 2^shift
+    pub(crate) fn shl(&self, shift: usize) -> Ball {
+        Ball {
+            center: shl_int(&self.center, shift),
+            radius: self.radius + shift as f64,
+            scale: self.scale,
+        }
+    }
+
+    /// self / 2^shift
+    pub(crate) fn shr(&self, shift: usize) -> Ball {
+        Ball {
+            center: shr_int(&self.center, shift),
+            radius: log_add(self.radius - shift as f64, 0.0),
+            scale: self.scale,
+        }
+    }
+
+    /// The same value with shift bits less of scale
+    pub(crate) fn rescaled(&self, shift: usize) -> Ball {
+        Ball {
+            scale: self.scale - shift,
+            ..self.shr(shift)
+        }
+    }
+
+    pub(crate) fn mul_int(&self, n: &BigInt) -> Ball {
+        Ball {
+            center: &self.center * n,
+            radius: self.radius + n.natural.bits() as f64,
+            scale: self.scale,
+        }
+    }
+
+    /// Widens the radius by 2^error units
+    pub(crate) fn widened(&self, error: f64) -> Ball {
+        Ball {
+            radius: log_add(self.radius, error),
+            ..self.clone()
+        }
+    }
+
+    /// The value of self * 2^shift as an approximation, unknown if the radius is unbounded
+    pub(crate) fn approximation(&self, shift: i64) -> Approximation {
+        if self.radius == f64::INFINITY {
+            return Approximation::Unknown;
+        }
+
+        let exponent = shift - self.scale as i64;
+        let value = BigFloat {
+            mantissa: self.center.clone(),
+            exponent,
+            precision: self.center.natural.bits().max(1),
+        };
+        let value = value.with_precision(value.precision, crate::RoundingMode::Down);
+        let error = self.radius.max(0.0).ceil() as i64 + exponent;
+        Approximation::Inexact(value, error)
+    }
+}
@@ -0,0 +1,45 @@
+use super::top;
+use crate::{APNum, BigFloat};
+
+/// By value, regardless of the precision
+impl std::cmp::PartialEq for BigFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.mantissa == other.mantissa && self.exponent == other.exponent
+    }
+}
+
+impl std::cmp::Eq for BigFloat {}
+
+impl std::cmp::Ord for BigFloat {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering::*;
+
+        if self.mantissa.sign != other.mantissa.sign || self.is_zero() {
+            return self.mantissa.cmp(&other.mantissa);
+        }
+
+        // Same sign, compare the magnitudes
+        let magnitude = match top(self).cmp(&top(other)) {
+            Equal => {
+                // The mantissas are aligned at the lower exponent, the shifts are shorter than the mantissas
+                let exponent = self.exponent.min(other.exponent);
+                let x = &self.mantissa.natural << (self.exponent - exponent) as usize;
+                let y = &other.mantissa.natural << (other.exponent - exponent) as usize;
+                x.cmp(&y)
+            }
+            ordering => ordering,
+        };
+
+        if self.is_negative() {
+            magnitude.reverse()
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl std::cmp::PartialOrd for BigFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
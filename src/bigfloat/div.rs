@@ -0,0 +1,34 @@
+use super::{checked_exponent, divide};
+use crate::{BigFloat, RoundingMode};
+
+impl BigFloat {
+    /// self / rhs rounded to precision bits
+    pub fn div_rounded(&self, rhs: &BigFloat, precision: usize, mode: RoundingMode) -> BigFloat {
+        divide(
+            self.is_negative() != rhs.is_negative(),
+            &self.mantissa.natural,
+            &rhs.mantissa.natural,
+            checked_exponent(self.exponent.checked_sub(rhs.exponent)),
+            precision,
+            mode,
+        )
+    }
+}
+
+/// Rounded to nearest with the larger precision of the operands
+impl std::ops::Div for &BigFloat {
+    type Output = BigFloat;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let precision = self.precision.max(rhs.precision);
+        self.div_rounded(rhs, precision, RoundingMode::HalfEven)
+    }
+}
+
+impl std::ops::Div for BigFloat {
+    type Output = BigFloat;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        (&self).div(&rhs)
+    }
+}
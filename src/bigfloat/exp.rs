@@ -0,0 +1,258 @@
+use std::f64::consts::LN_2;
+
+use super::ball::{shl_int, Ball};
+use super::log::ln_ball;
+use super::series;
+use super::ziv::{transcendental, Approximation};
+use super::{checked_exponent, top};
+use crate::{APNum, BigDigit, BigFloat, BigInt, BigRational, RoundingMode, Sign};
+
+/// Integer powers are exact up to this number of bits
+const EXACT_POWER_BITS: u64 = 1 << 20;
+
+impl BigFloat {
+    transcendental! {
+        "e^self" exp exp_rounded exp_decimal => exp;
+        "Hyperbolic sine of self" sinh sinh_rounded sinh_decimal => sinh;
+        "Hyperbolic cosine of self" cosh cosh_rounded cosh_decimal => cosh;
+    }
+
+    /// self^exponent rounded to nearest with the larger precision of the operands
+    pub fn pow(&self, exponent: &BigFloat) -> BigFloat {
+        let precision = self.precision.max(exponent.precision);
+        self.pow_rounded(exponent, precision, RoundingMode::HalfEven)
+    }
+
+    /// self^exponent rounded to precision bits
+    pub fn pow_rounded(
+        &self,
+        exponent: &BigFloat,
+        precision: usize,
+        mode: RoundingMode,
+    ) -> BigFloat {
+        super::ziv::rounded(precision, mode, |working| pow(self, exponent, working))
+    }
+
+    /// self^exponent rounded to the given number of significant decimal digits
+    pub fn pow_decimal(&self, exponent: &BigFloat, digits: usize, mode: RoundingMode) -> String {
+        super::ziv::decimal(digits, mode, |working| pow(self, exponent, working))
+    }
+}
+
+/// Number of halvings of the argument before the series
+pub(crate) fn halvings(scale: usize) -> usize {
+    (scale as f64).sqrt() as usize / 2
+}
+
+/// Guard bits for exp at the working precision, when the result is about 2^(2^bits)
+fn guard(working: usize, bits: u32) -> usize {
+    2 * halvings(working) + bits as usize + 16
+}
+
+/// (y, n) with e^x = y 2^n, y about 1 at the scale of x
+pub(crate) fn exp_ball(x: &Ball) -> (Ball, i64) {
+    let scale = x.scale;
+
+    // x = n ln 2 + r with |r| about ln 2 / 2 at most
+    let n = (x.to_f64() / LN_2).round() as i64;
+    let r = x.sub(&series::ln2(scale).mul_int(&BigInt::from(n)));
+
+    // e^r = (e^(r / 2^s))^(2^s)
+    let halvings = halvings(scale);
+    let mut y = series::exp(&r, halvings);
+    for _ in 0..halvings {
+        y = y.mul(&y);
+    }
+    (y, n)
+}
+
+fn check_overflow(x: &BigFloat) {
+    if top(x) > 62 {
+        panic!("Exponent overflow");
+    }
+}
+
+fn exp(x: &BigFloat, working: usize) -> Approximation {
+    if x.is_zero() {
+        return Approximation::Exact(BigRational::from(1));
+    }
+    check_overflow(x);
+
+    let scale = working + guard(working, top(x).max(0) as u32 + 1);
+    let (y, n) = exp_ball(&Ball::from_float(x, scale));
+    y.approximation(n)
+}
+
+fn sinh(x: &BigFloat, working: usize) -> Approximation {
+    if x.is_zero() {
+        return Approximation::Exact(BigRational::zero());
+    }
+    hyperbolic(x, working, true)
+}
+
+fn cosh(x: &BigFloat, working: usize) -> Approximation {
+    if x.is_zero() {
+        return Approximation::Exact(BigRational::from(1));
+    }
+    hyperbolic(x, working, false)
+}
+
+/// sinh x or cosh x from e^|x| = y 2^n, as 2^(n - 1) (y ∓ 2^-2n / y)
+fn hyperbolic(x: &BigFloat, working: usize, sinh: bool) -> Approximation {
+    check_overflow(x);
+
+    // sinh x is about x for small x, the error has to be below it
+    let small = (-top(x)).max(0) as usize;
+    let scale = working + small + guard(working, top(x).max(0) as u32 + 1);
+    let (y, n) = exp_ball(&Ball::from_float(&x.abs(), scale));
+
+    // Below a unit when shifted that far
+    let shift = 2 * n as u64;
+    let inverse = if shift > scale as u64 + 2 {
+        Ball {
+            center: BigInt::zero(),
+            radius: 0.0,
+            scale,
+        }
+    } else {
+        Ball::one(scale).div(&y).shr(shift as usize)
+    };
+
+    let z = if sinh {
+        y.sub(&inverse)
+    } else {
+        y.add(&inverse)
+    };
+    let z = if sinh && x.is_negative() { z.neg() } else { z };
+    z.approximation(n - 1)
+}
+
+fn pow(x: &BigFloat, y: &BigFloat, working: usize) -> Approximation {
+    if y.is_zero() {
+        return Approximation::Exact(BigRational::from(1));
+    }
+    if x.is_zero() {
+        if y.is_negative() {
+            panic!("Division by Zero");
+        }
+        return Approximation::Exact(BigRational::zero());
+    }
+
+    let integer = y.exponent >= 0;
+    if x.is_negative() && !integer {
+        panic!("Non-integer power of a negative number");
+    }
+
+    // Odd powers of negative numbers
+    let negative = x.is_negative() && y.exponent == 0;
+    if x.exponent == 0 && x.mantissa.natural.bits() == 1 {
+        let one = BigRational::from(1);
+        return Approximation::Exact(if negative { -one } else { one });
+    }
+    if let Some(value) = exact_pow(x, y, negative) {
+        return value;
+    }
+
+    // x^y = e^(y ln|x|), with |y ln|x|| < 2^(top(y) + bits of top(x))
+    let log_bits = u64::BITS - (top(x).unsigned_abs() + 1).leading_zeros();
+    let bits = top(y).max(0) as u32 + log_bits;
+    if bits > 62 {
+        panic!("Exponent overflow");
+    }
+
+    // The error of the logarithm is multiplied by y
+    let scale = working + top(y).max(0) as usize + guard(working, bits);
+    let product = ln_ball(&x.abs(), scale).mul(&Ball::from_float(y, scale));
+    let (z, n) = exp_ball(&product);
+    let z = if negative { z.neg() } else { z };
+    z.approximation(n)
+}
+
+/// x^y when it is rational and not too long, for x and y nonzero and |x| not one, negative tells
+/// the sign of the result
+fn exact_pow(x: &BigFloat, y: &BigFloat, negative: bool) -> Option<Approximation> {
+    // y = m / 2^k, x^y = (x^(1/2^k))^m, larger powers overflow the exponent of any x but one
+    let k = (-y.exponent).max(0) as u64;
+    if top(y) > 62 {
+        return None;
+    }
+    let m = shl_int(&y.mantissa, y.exponent.max(0) as usize);
+    if m.natural.bits() > 62 {
+        return None;
+    }
+    let power = (m.natural.digits.iter().rev())
+        .fold(0, |power, digit| power << BigDigit::BITS | *digit as i64);
+    let power = if m.is_negative() { -power } else { power };
+
+    let root = if k == 0 {
+        x.clone()
+    } else {
+        // x = mantissa 2^exponent is a 2^k-th power if and only if both the mantissa and the
+        // exponent are
+        let magnitude = &x.mantissa.natural;
+        if k > 62 || x.exponent % (1 << k) != 0 {
+            return None;
+        }
+
+        let root = if magnitude.bits() == 1 {
+            magnitude.clone()
+        } else {
+            if 1 << k > magnitude.bits() as u64 {
+                return None;
+            }
+            let root = magnitude.nth_root(1 << k);
+            if root.pow(1 << k) != *magnitude {
+                return None;
+            }
+            root
+        };
+
+        BigFloat {
+            mantissa: BigInt::from(root),
+            exponent: x.exponent >> k,
+            precision: x.precision,
+        }
+    };
+
+    // The trailing zeros of the mantissa only scale the result by a power of two, so the mantissa
+    // is the only part which grows with the power
+    let zeros = root.mantissa.natural.trailing_zeros()?;
+    let mantissa = &root.mantissa.natural >> zeros;
+    let exponent = checked_exponent(root.exponent.checked_add(zeros as i64));
+    let exponent = checked_exponent(exponent.checked_mul(power));
+    let sign = if negative {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+
+    // (m 2^e)^power = m^power 2^(e power) for a nonnegative power, always exact for |x| = 2^e
+    let bits = (mantissa.bits() as u64).saturating_mul(power.unsigned_abs());
+    if power > 0 || mantissa.bits() == 1 {
+        let mantissa = if mantissa.bits() == 1 {
+            mantissa
+        } else if bits > EXACT_POWER_BITS {
+            return None;
+        } else {
+            mantissa.pow(power as u32)
+        };
+        let precision = mantissa.bits();
+        let mantissa = BigInt {
+            sign,
+            natural: mantissa,
+        };
+        return Some(Approximation::ExactFloat(BigFloat {
+            mantissa,
+            exponent,
+            precision,
+        }));
+    }
+
+    // 1 / m^|power| is not a float, the power of two of the rational has to be short as well
+    if bits + exponent.unsigned_abs() > EXACT_POWER_BITS {
+        return None;
+    }
+    Some(Approximation::Exact(
+        BigRational::from(&root).pow(power as i32),
+    ))
+}
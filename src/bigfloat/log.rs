@@ -0,0 +1,104 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+
+use super::ball::Ball;
+use super::exp::halvings;
+use super::series;
+use super::top;
+use super::ziv::{transcendental, Approximation};
+use crate::{APNum, BigFloat, BigInt, BigNat, BigRational, RoundingMode};
+
+impl BigFloat {
+    transcendental! {
+        "Natural logarithm of self" ln ln_rounded ln_decimal => ln;
+        "Base 2 logarithm of self" log2 log2_rounded log2_decimal => log2;
+        "Base 10 logarithm of self" log10 log10_rounded log10_decimal => log10;
+    }
+}
+
+/// ln x for positive x at the scale
+pub(crate) fn ln_ball(x: &BigFloat, scale: usize) -> Ball {
+    // x = 2^t y with y in [√½; √2)
+    let mut t = top(x);
+    let mut y = BigFloat {
+        exponent: x.exponent - t,
+        ..x.clone()
+    };
+    if y.to_f64(RoundingMode::HalfEven) < FRAC_1_SQRT_2 {
+        t -= 1;
+        y.exponent += 1;
+    }
+
+    // ln y = 2^(s + 1) atanh((z - 1) / (z + 1)) with z = y^(1 / 2^s)
+    let halvings = halvings(scale).max(1);
+    let one = Ball::one(scale);
+    let mut z = Ball::from_float(&y, scale);
+    for _ in 0..halvings {
+        z = z.sqrt();
+    }
+    let u = z.sub(&one).div(&z.add(&one));
+
+    series::atan(&u, true)
+        .shl(halvings + 1)
+        .add(&series::ln2(scale).mul_int(&BigInt::from(t)))
+}
+
+/// Guard bits for the logarithms at the working precision: the ones lost by the halvings, by the
+/// multiple of ln 2 and by the cancellation near 1
+fn guard(x: &BigFloat, working: usize) -> usize {
+    let near_one = if (0..=1).contains(&top(x)) {
+        let one = BigFloat::from_rational(&BigRational::from(1), 1, RoundingMode::Down);
+        let precision = x.mantissa.natural.bits() + x.exponent.unsigned_abs() as usize + 2;
+        let difference = x.sub_rounded(&one, precision, RoundingMode::Down);
+        (-top(&difference)).max(0) as usize
+    } else {
+        0
+    };
+
+    let multiple = (u64::BITS - top(x).unsigned_abs().leading_zeros()) as usize;
+    halvings(working) + multiple + near_one + 16
+}
+
+fn check_positive(x: &BigFloat) {
+    if !x.is_positive() {
+        panic!("Logarithm of a non-positive number");
+    }
+}
+
+fn ln(x: &BigFloat, working: usize) -> Approximation {
+    check_positive(x);
+    if x.exponent == 0 && x.mantissa.natural.bits() == 1 {
+        return Approximation::Exact(BigRational::zero());
+    }
+
+    let scale = working + guard(x, working);
+    ln_ball(x, scale).approximation(0)
+}
+
+fn log2(x: &BigFloat, working: usize) -> Approximation {
+    check_positive(x);
+    if x.mantissa.natural.bits() == 1 {
+        return Approximation::Exact(BigRational::from(x.exponent));
+    }
+
+    let scale = working + guard(x, working);
+    ln_ball(x, scale).div(&series::ln2(scale)).approximation(0)
+}
+
+fn log10(x: &BigFloat, working: usize) -> Approximation {
+    check_positive(x);
+
+    // 10^k = 5^k 2^k, where 5^k has more than 2k bits
+    let magnitude = &x.mantissa.natural;
+    if x.exponent >= 0
+        && magnitude.bits() as u64 > 2 * x.exponent as u64
+        && *magnitude == BigNat::from(5usize).pow(x.exponent as u32)
+    {
+        return Approximation::Exact(BigRational::from(x.exponent));
+    }
+
+    let scale = working + guard(x, working);
+    let ten = BigFloat::from_rational(&BigRational::from(10), 4, RoundingMode::Down);
+    ln_ball(x, scale)
+        .div(&ln_ball(&ten, scale))
+        .approximation(0)
+}
@@ -0,0 +1,601 @@
+pub mod add;
+pub mod ball;
+pub mod cmp;
+pub mod div;
+pub mod exp;
+pub mod log;
+pub mod mul;
+pub mod root;
+pub mod series;
+pub mod sub;
+pub mod trig;
+pub mod ziv;
+
+use std::f64::consts::{LOG10_2, LOG2_10};
+
+use crate::rounding::{div_rounded, shr_rounded};
+use crate::{
+    APNum, APNumParseError, BigFloat, BigInt, BigNat, BigRational, RoundingMode, Sign,
+};
+
+/// Precision of the values created without one, the one of f64
+const DEFAULT_PRECISION: usize = 53;
+
+impl BigFloat {
+    /// mantissa * 2^exponent rounded to precision bits
+    pub fn new(mantissa: BigInt, exponent: i64, precision: usize, mode: RoundingMode) -> Self {
+        round(
+            mantissa.is_negative(),
+            &mantissa.natural,
+            exponent,
+            false,
+            precision,
+            mode,
+        )
+    }
+
+    /// Odd mantissa (zero for zero)
+    pub fn mantissa(&self) -> &BigInt {
+        &self.mantissa
+    }
+
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.mantissa.is_negative()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.mantissa.is_positive()
+    }
+
+    pub fn abs(&self) -> Self {
+        BigFloat {
+            mantissa: self.mantissa.abs(),
+            ..self.clone()
+        }
+    }
+
+    /// self rounded to the given precision
+    pub fn with_precision(&self, precision: usize, mode: RoundingMode) -> Self {
+        round(
+            self.is_negative(),
+            &self.mantissa.natural,
+            self.exponent,
+            false,
+            precision,
+            mode,
+        )
+    }
+
+    /// The rational value rounded to precision bits
+    pub fn from_rational(value: &BigRational, precision: usize, mode: RoundingMode) -> Self {
+        divide(
+            value.is_negative(),
+            &value.numerator.natural,
+            &value.denominator,
+            0,
+            precision,
+            mode,
+        )
+    }
+
+    /// Parses a decimal string like "-1.25e-3" rounded to precision bits
+    pub fn parse(s: &str, precision: usize, mode: RoundingMode) -> Result<Self, APNumParseError> {
+        let (n, exponent) = parse_scientific(s)?;
+        from_scientific(&n, exponent, precision, mode)
+    }
+
+    /// Decimal representation of self rounded to the given number of significant digits
+    pub fn to_decimal(&self, digits: usize, mode: RoundingMode) -> String {
+        if digits == 0 {
+            panic!("Zero significant digits");
+        }
+
+        if self.is_zero() {
+            return "0".to_string();
+        }
+
+        // Decimal exponent k with 10^k ≤ |self| < 10^(k + 1), starting from the estimate by the
+        // bits. |self| * 10^(digits - 1 - k) is bounded at a working precision until both bounds
+        // round to the same integer, so the numbers stay about digits long for any exponent.
+        let magnitude = self.abs();
+        let mut k = ((top(self) - 1) as f64 * LOG10_2).floor() as i64;
+        let mut working = (digits as f64 * LOG2_10).ceil() as usize + 32;
+        let significand = loop {
+            let scale = checked_exponent((digits as i64 - 1).checked_sub(k));
+            let (low, high) = scaled_bounds(&magnitude, scale, working);
+            let low = integer_rounded(&low, self.is_negative(), mode);
+            if low != integer_rounded(&high, self.is_negative(), mode) {
+                working += working / 2;
+                continue;
+            }
+
+            match low.decimal_digit_count().cmp(&digits) {
+                std::cmp::Ordering::Greater => k += 1,
+                std::cmp::Ordering::Less => k -= 1,
+                std::cmp::Ordering::Equal => break low,
+            }
+        };
+        decimal_notation(self.is_negative(), &significand, k)
+    }
+
+    /// Nearest f64 in the direction of the mode, out of range values become infinite
+    /// (or the largest finite f64 when rounding toward zero)
+    pub fn to_f64(&self, mode: RoundingMode) -> f64 {
+        if self.is_zero() {
+            return 0.0;
+        }
+
+        let negative = self.is_negative();
+        let magnitude = &self.mantissa.natural;
+
+        // Weight of the last bit of the result, 53 bits below the top or the one of the smallest subnormal
+        let top = magnitude.bits() as i64 + self.exponent;
+        let mut lowest = (top - 53).max(-1074);
+        let rounded = if self.exponent >= lowest {
+            magnitude << (self.exponent - lowest) as usize
+        } else {
+            let shift = (lowest - self.exponent) as usize;
+            shr_rounded(magnitude, shift, false, negative, mode)
+        };
+
+        let mut significand = rounded
+            .digits
+            .iter()
+            .rev()
+            .fold(0u64, |acc, digit| acc << 32 | *digit as u64);
+
+        // Rounded up to the next power of two
+        if significand == 1 << 53 {
+            significand >>= 1;
+            lowest += 1;
+        }
+
+        let bits = if significand < 1 << 52 {
+            // Subnormal
+            significand
+        } else {
+            let biased = lowest + 52 + 1023;
+            if biased >= 2047 {
+                let infinite = match mode {
+                    RoundingMode::HalfEven | RoundingMode::HalfUp => true,
+                    RoundingMode::Down => false,
+                    RoundingMode::Ceiling => !negative,
+                    RoundingMode::Floor => negative,
+                };
+                let magnitude = if infinite { f64::INFINITY } else { f64::MAX };
+                return if negative { -magnitude } else { magnitude };
+            }
+
+            (biased as u64) << 52 | (significand - (1 << 52))
+        };
+
+        let bits = if negative { bits | 1 << 63 } else { bits };
+        f64::from_bits(bits)
+    }
+}
+
+/// Position right above the highest bit, |x| < 2^top
+pub(crate) fn top(x: &BigFloat) -> i64 {
+    x.mantissa.natural.bits() as i64 + x.exponent
+}
+
+/// (-1)^negative * magnitude * 2^exponent rounded to precision bits. sticky tells that the actual
+/// magnitude is a little more than the given one (less than its last unit), the magnitude has to
+/// have more than precision bits then.
+pub(crate) fn round(
+    negative: bool,
+    magnitude: &BigNat,
+    exponent: i64,
+    sticky: bool,
+    precision: usize,
+    mode: RoundingMode,
+) -> BigFloat {
+    if precision == 0 {
+        panic!("Zero precision");
+    }
+
+    let bits = magnitude.bits();
+    if bits <= precision {
+        debug_assert!(!sticky);
+        return normalized(negative, magnitude.clone(), exponent, precision);
+    }
+
+    let shift = bits - precision;
+    let magnitude = shr_rounded(magnitude, shift, sticky, negative, mode);
+    let exponent = checked_exponent(exponent.checked_add(shift as i64));
+    normalized(negative, magnitude, exponent, precision)
+}
+
+/// (-1)^negative * numerator / denominator * 2^exponent rounded to precision bits
+pub(crate) fn divide(
+    negative: bool,
+    numerator: &BigNat,
+    denominator: &BigNat,
+    exponent: i64,
+    precision: usize,
+    mode: RoundingMode,
+) -> BigFloat {
+    if denominator.is_zero() {
+        panic!("Division by Zero");
+    }
+
+    // At least precision + 2 bits of quotient, and the remainder as the sticky bit
+    let shift = (precision + 2 + denominator.bits()).saturating_sub(numerator.bits());
+    let (quotient, remainder) = &(numerator << shift) / denominator;
+    round(
+        negative,
+        &quotient,
+        checked_exponent(exponent.checked_sub(shift as i64)),
+        !remainder.is_zero(),
+        precision,
+        mode,
+    )
+}
+
+/// Decimal representation of (-1)^negative * numerator / denominator (nonzero) rounded to the given
+/// number of significant digits
+pub(crate) fn decimal_string(
+    negative: bool,
+    numerator: &BigNat,
+    denominator: &BigNat,
+    digits: usize,
+    mode: RoundingMode,
+) -> String {
+    // Decimal exponent k with 10^k ≤ |value| < 10^(k + 1), starting from the estimate by the bits
+    let top = numerator.bits() as i64 - denominator.bits() as i64 + 1;
+    let mut k = ((top - 1) as f64 * LOG10_2).floor() as i64;
+    let significand = loop {
+        // |value| * 10^(digits - 1 - k) rounded to an integer
+        let scale = digits as i64 - 1 - k;
+        let power = u32::try_from(scale.unsigned_abs()).expect("Decimal exponent out of range");
+        let power = BigNat::from(10usize).pow(power);
+        let significand = if scale >= 0 {
+            div_rounded(&(numerator * &power), denominator, negative, mode)
+        } else {
+            div_rounded(numerator, &(denominator * &power), negative, mode)
+        };
+        match significand.decimal_digit_count().cmp(&digits) {
+            std::cmp::Ordering::Greater => k += 1,
+            std::cmp::Ordering::Less => k -= 1,
+            std::cmp::Ordering::Equal => break significand,
+        }
+    };
+
+    decimal_notation(negative, &significand, k)
+}
+
+/// Decimal notation of (-1)^negative * significand * 10^(k + 1 - its digits), scientific with one
+/// digit before the point outside of 10^-7 ≤ |value| < 10^21
+fn decimal_notation(negative: bool, significand: &BigNat, k: i64) -> String {
+    let significand = significand.to_string();
+    let significand = significand.trim_end_matches('0');
+    let sign = if negative { "-" } else { "" };
+
+    if !(-7..21).contains(&k) {
+        return match significand.split_at(1) {
+            (first, "") => format!("{sign}{first}e{k}"),
+            (first, rest) => format!("{sign}{first}.{rest}e{k}"),
+        };
+    }
+
+    if k < 0 {
+        let zeros = "0".repeat(k.unsigned_abs() as usize - 1);
+        return format!("{sign}0.{zeros}{significand}");
+    }
+
+    let integer_digits = k as usize + 1;
+    if significand.len() <= integer_digits {
+        let zeros = "0".repeat(integer_digits - significand.len());
+        format!("{sign}{significand}{zeros}")
+    } else {
+        let (integer, fraction) = significand.split_at(integer_digits);
+        format!("{sign}{integer}.{fraction}")
+    }
+}
+
+/// Bounds of x * 10^scale for a positive x, rounded to about working bits. The power of ten is
+/// exact while it has at most working bits, the bounds are equal then when the product is short.
+fn scaled_bounds(x: &BigFloat, scale: i64, working: usize) -> (BigFloat, BigFloat) {
+    let n = scale.unsigned_abs();
+    let (low, high) = if n as f64 * LOG2_10 <= working as f64 {
+        let power = BigNat::from(10usize).pow(n as u32);
+        let bits = power.bits();
+        let power = BigFloat::new(BigInt::from(power), 0, bits, RoundingMode::Down);
+        (power.clone(), power)
+    } else {
+        (
+            power_of_ten(n, working, RoundingMode::Floor),
+            power_of_ten(n, working, RoundingMode::Ceiling),
+        )
+    };
+
+    let precision = working + x.mantissa.natural.bits();
+    if scale >= 0 {
+        (
+            x.mul_rounded(&low, precision, RoundingMode::Floor),
+            x.mul_rounded(&high, precision, RoundingMode::Ceiling),
+        )
+    } else {
+        (
+            x.div_rounded(&high, precision, RoundingMode::Floor),
+            x.div_rounded(&low, precision, RoundingMode::Ceiling),
+        )
+    }
+}
+
+/// 10^n rounded to precision bits, toward the mode at every step (Floor or Ceiling)
+fn power_of_ten(n: u64, precision: usize, mode: RoundingMode) -> BigFloat {
+    let ten = BigFloat::new(BigInt::from(10), 0, precision, mode);
+    let mut power = BigFloat::new(BigInt::from(1), 0, precision, mode);
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        power = power.mul_rounded(&power, precision, mode);
+        if n >> bit & 1 == 1 {
+            power = power.mul_rounded(&ten, precision, mode);
+        }
+    }
+    power
+}
+
+/// A positive x rounded to an integer, as the magnitude of a value of the given sign
+fn integer_rounded(x: &BigFloat, negative: bool, mode: RoundingMode) -> BigNat {
+    if x.exponent >= 0 {
+        &x.mantissa.natural << x.exponent as usize
+    } else {
+        shr_rounded(
+            &x.mantissa.natural,
+            x.exponent.unsigned_abs() as usize,
+            false,
+            negative,
+            mode,
+        )
+    }
+}
+
+/// The result of an exponent computation, panics on overflow
+pub(crate) fn checked_exponent(exponent: Option<i64>) -> i64 {
+    exponent.unwrap_or_else(|| panic!("Exponent overflow"))
+}
+
+/// Moves the trailing zeros of the magnitude to the exponent
+fn normalized(negative: bool, magnitude: BigNat, exponent: i64, precision: usize) -> BigFloat {
+    let Some(zeros) = magnitude.trailing_zeros() else {
+        return BigFloat {
+            mantissa: BigInt::zero(),
+            exponent: 0,
+            precision,
+        };
+    };
+
+    let sign = if negative {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+
+    BigFloat {
+        mantissa: BigInt {
+            sign,
+            natural: &magnitude >> zeros,
+        },
+        exponent: checked_exponent(exponent.checked_add(zeros as i64)),
+        precision,
+    }
+}
+
+/// Splits a decimal string like "-123.4500e-7" into (-1234500, -11), the value is n * 10^e
+pub(crate) fn parse_scientific(s: &str) -> Result<(BigInt, i64), APNumParseError> {
+    if s.is_empty() {
+        return Err(APNumParseError::Empty);
+    }
+
+    let (s, exponent) = match s.split_once(['e', 'E']) {
+        Some((s, exponent)) => (
+            s,
+            exponent
+                .parse::<i64>()
+                .map_err(|_| APNumParseError::Invalid)?,
+        ),
+        None => (s, 0),
+    };
+
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (Sign::Negative, s),
+        None => (Sign::Positive, s),
+    };
+
+    let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if s.contains('.') && (integer.is_empty() || fraction.is_empty()) {
+        return Err(APNumParseError::Invalid);
+    }
+
+    let natural = format!("{integer}{fraction}").parse::<BigNat>()?;
+    let exponent = exponent
+        .checked_sub(fraction.len() as i64)
+        .ok_or(APNumParseError::Invalid)?;
+    Ok((BigInt { sign, natural }.zero_normalized(), exponent))
+}
+
+/// n * 10^exponent rounded to precision bits, fails for exponents past 32 bits
+fn from_scientific(
+    n: &BigInt,
+    exponent: i64,
+    precision: usize,
+    mode: RoundingMode,
+) -> Result<BigFloat, APNumParseError> {
+    // 10^e = 5^e * 2^e
+    let power = u32::try_from(exponent.unsigned_abs()).map_err(|_| APNumParseError::Invalid)?;
+    let power = BigNat::from(5usize).pow(power);
+    Ok(if exponent >= 0 {
+        round(
+            n.is_negative(),
+            &(&n.natural * &power),
+            exponent,
+            false,
+            precision,
+            mode,
+        )
+    } else {
+        divide(
+            n.is_negative(),
+            &n.natural,
+            &power,
+            exponent,
+            precision,
+            mode,
+        )
+    })
+}
+
+impl APNum for BigFloat {
+    fn zero() -> Self {
+        BigFloat {
+            mantissa: BigInt::zero(),
+            exponent: 0,
+            precision: DEFAULT_PRECISION,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    fn zero_normalized(self) -> Self {
+        self
+    }
+
+    fn digit_count(&self) -> usize {
+        self.mantissa.digit_count()
+    }
+}
+
+impl std::ops::Neg for &BigFloat {
+    type Output = BigFloat;
+
+    fn neg(self) -> Self::Output {
+        BigFloat {
+            mantissa: -&self.mantissa,
+            ..self.clone()
+        }
+    }
+}
+
+impl std::ops::Neg for BigFloat {
+    type Output = BigFloat;
+
+    fn neg(self) -> Self::Output {
+        -(&self)
+    }
+}
+
+/// Exact, with the precision of f64
+impl TryFrom<f64> for BigFloat {
+    type Error = ();
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(());
+        }
+
+        let bits = value.to_bits();
+        let negative = bits >> 63 == 1;
+        let biased = (bits >> 52 & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (significand, exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased - 1075)
+        };
+
+        Ok(normalized(
+            negative,
+            BigNat::from(significand),
+            exponent,
+            DEFAULT_PRECISION,
+        ))
+    }
+}
+
+/// Exact
+impl From<&BigFloat> for BigRational {
+    fn from(value: &BigFloat) -> Self {
+        let one = BigNat::from(1usize);
+        if value.exponent >= 0 {
+            BigRational::from(&value.mantissa * &BigInt::from(&one << value.exponent as usize))
+        } else {
+            BigRational::new(
+                value.mantissa.clone(),
+                &one << value.exponent.unsigned_abs() as usize,
+            )
+        }
+    }
+}
+
+impl std::str::FromStr for BigFloat {
+    type Err = APNumParseError;
+
+    /// Rounded to nearest, with enough precision for the given digits (at least the one of f64)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (n, exponent) = parse_scientific(s)?;
+        let digits = n.natural.decimal_digit_count();
+        let precision = DEFAULT_PRECISION.max((digits as f64 * LOG2_10).ceil() as usize);
+        from_scientific(&n, exponent, precision, RoundingMode::HalfEven)
+    }
+}
+
+impl TryFrom<&str> for BigFloat {
+    type Error = APNumParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// With enough significant digits to parse back to the same value at the same precision
+impl std::fmt::Display for BigFloat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = (self.precision as f64 * LOG10_2).ceil() as usize + 1;
+        write!(f, "{}", self.to_decimal(digits, RoundingMode::HalfEven))
+    }
+}
+
+impl std::fmt::Debug for BigFloat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_scientific;
+    use crate::{APNumParseError, BigInt};
+
+    #[test]
+    fn scientific() {
+        let result = parse_scientific("-123.4500e-7");
+        assert!(result.is_ok_and(|(n, exponent)| n == BigInt::from(-1234500) && exponent == -11))
+    }
+
+    #[test]
+    fn scientific_without_exponent() {
+        let result = parse_scientific("0.25");
+        assert!(result.is_ok_and(|(n, exponent)| n == BigInt::from(25) && exponent == -2))
+    }
+
+    #[test]
+    fn scientific_invalid() {
+        let result = parse_scientific("1.5e");
+        assert!(result.is_err_and(|err| matches!(err, APNumParseError::Invalid)));
+        let result = parse_scientific("1.e5");
+        assert!(result.is_err_and(|err| matches!(err, APNumParseError::Invalid)));
+        let result = parse_scientific("-");
+        assert!(result.is_err_and(|err| matches!(err, APNumParseError::Empty)))
+    }
+}
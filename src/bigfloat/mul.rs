@@ -0,0 +1,34 @@
+use super::{checked_exponent, round};
+use crate::{BigFloat, RoundingMode};
+
+impl BigFloat {
+    /// self * rhs rounded to precision bits
+    pub fn mul_rounded(&self, rhs: &BigFloat, precision: usize, mode: RoundingMode) -> BigFloat {
+        round(
+            self.is_negative() != rhs.is_negative(),
+            &(&self.mantissa.natural * &rhs.mantissa.natural),
+            checked_exponent(self.exponent.checked_add(rhs.exponent)),
+            false,
+            precision,
+            mode,
+        )
+    }
+}
+
+/// Rounded to nearest with the larger precision of the operands
+impl std::ops::Mul for &BigFloat {
+    type Output = BigFloat;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let precision = self.precision.max(rhs.precision);
+        self.mul_rounded(rhs, precision, RoundingMode::HalfEven)
+    }
+}
+
+impl std::ops::Mul for BigFloat {
+    type Output = BigFloat;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        (&self).mul(&rhs)
+    }
+}
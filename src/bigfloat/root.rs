@@ -0,0 +1,59 @@
+use super::round;
+use super::ziv::{decimal, Approximation};
+use crate::{APNum, BigFloat, BigInt, BigRational, RoundingMode};
+
+impl BigFloat {
+    /// Square root rounded to nearest with the precision of self
+    pub fn sqrt(&self) -> BigFloat {
+        self.sqrt_rounded(self.precision, RoundingMode::HalfEven)
+    }
+
+    /// Square root rounded to precision bits
+    pub fn sqrt_rounded(&self, precision: usize, mode: RoundingMode) -> BigFloat {
+        if self.is_negative() {
+            panic!("Square root of a negative number");
+        }
+
+        // Shift the mantissa so that it has at least 2 * precision + 4 bits and an even exponent,
+        // then the integer square root has precision + 2 bits and the remainder is the sticky bit
+        let magnitude = &self.mantissa.natural;
+        let mut shift = (2 * precision + 4).saturating_sub(magnitude.bits());
+        if (self.exponent - shift as i64).rem_euclid(2) == 1 {
+            shift += 1;
+        }
+
+        let (root, remainder) = (magnitude << shift).sqrt_rem();
+        round(
+            false,
+            &root,
+            (self.exponent - shift as i64) / 2,
+            !remainder.is_zero(),
+            precision,
+            mode,
+        )
+    }
+
+    /// Square root rounded to the given number of significant decimal digits
+    pub fn sqrt_decimal(&self, digits: usize, mode: RoundingMode) -> String {
+        decimal(digits, mode, |working| sqrt(self, working))
+    }
+}
+
+fn sqrt(x: &BigFloat, working: usize) -> Approximation {
+    // Exact for the squares: the mantissa shifted to an even exponent is a square
+    let odd = x.exponent.rem_euclid(2) as usize;
+    let (root, remainder) = (&x.mantissa.natural << odd).sqrt_rem();
+    if remainder.is_zero() {
+        let root = BigFloat {
+            mantissa: BigInt::from(root),
+            exponent: (x.exponent - odd as i64) / 2,
+            precision: working,
+        };
+        return Approximation::Exact(BigRational::from(&root));
+    }
+
+    // Rounded down, within a unit of the last place
+    let root = x.sqrt_rounded(working, RoundingMode::Down);
+    let error = super::top(&root) - working as i64;
+    Approximation::Inexact(root, error)
+}
@@ -0,0 +1,205 @@
+// Series of rational numbers by binary splitting, and the constants needed by the transcendental
+// functions
+
+use super::ball::{div_int, shl_int, Ball};
+use crate::BigInt;
+
+/// Term k of a series sum_k a(k) / b(k) * prod_{j ≤ k} p(j) / q(j), with b and q positive
+pub(crate) struct Term {
+    pub(crate) a: BigInt,
+    pub(crate) b: BigInt,
+    pub(crate) p: BigInt,
+    pub(crate) q: BigInt,
+}
+
+impl Term {
+    /// p / q as the ratio to the previous term
+    pub(crate) fn ratio(p: BigInt, q: BigInt) -> Term {
+        Term {
+            a: BigInt::from(1),
+            b: BigInt::from(1),
+            p,
+            q,
+        }
+    }
+}
+
+/// (P, Q, B, T) of the terms in [start; end), the partial sum is T / (B Q)
+// see. Haible and Papanikolaou, Fast multiprecision evaluation of series of rational numbers (1998)
+fn split(start: u64, end: u64, term: &impl Fn(u64) -> Term) -> (BigInt, BigInt, BigInt, BigInt) {
+    if end - start == 1 {
+        let Term { a, b, p, q } = term(start);
+        let t = &a * &p;
+        return (p, q, b, t);
+    }
+
+    let middle = start + (end - start) / 2;
+    let (lp, lq, lb, lt) = split(start, middle, term);
+    let (rp, rq, rb, rt) = split(middle, end, term);
+    let t = &(&(&rb * &rq) * &lt) + &(&(&lb * &lp) * &rt);
+    (&lp * &rp, &lq * &rq, &lb * &rb, t)
+}
+
+/// The sum of the first count terms of the series at the scale, the caller bounds the rest of the
+/// series by a unit
+pub(crate) fn sum(count: u64, term: impl Fn(u64) -> Term, scale: usize) -> Ball {
+    let (_, q, b, t) = split(0, count.max(1), &term);
+    Ball {
+        center: div_int(&shl_int(&t, scale), &(&b * &q)),
+        // A unit for the rest of the series and one for the truncation
+        radius: 1.0,
+        scale,
+    }
+}
+
+/// Number of terms after which the terms fall below 2^-bits, given the log2 of the terms
+pub(crate) fn term_count(bits: usize, log_term: impl Fn(u64) -> f64) -> u64 {
+    (1..).find(|&k| log_term(k) < -(bits as f64)).unwrap()
+}
+
+/// log2(k!)
+pub(crate) fn log_factorial(k: u64) -> f64 {
+    (2..=k).map(|j| (j as f64).log2()).sum()
+}
+
+/// exp(x / 2^shift) for |x / 2^shift| ≤ 1/2, the error of x is scaled by the derivative
+pub(crate) fn exp(x: &Ball, shift: usize) -> Ball {
+    let scale = x.scale;
+    let log_x = x.upper() - shift as f64;
+    let count = term_count(scale + 2, |k| k as f64 * log_x - log_factorial(k));
+    let denominator = shl_int(&BigInt::from(1), scale + shift);
+    let sum = sum(
+        count,
+        |k| match k {
+            0 => Term::ratio(BigInt::from(1), BigInt::from(1)),
+            _ => Term::ratio(x.center.clone(), &BigInt::from(k) * &denominator),
+        },
+        scale,
+    );
+
+    // exp' ≤ 2 for |x| ≤ 1/2
+    sum.widened(x.radius - shift as f64 + 1.0)
+}
+
+/// (sin(x / 2^shift), cos(x / 2^shift)) for |x / 2^shift| ≤ 1/2
+pub(crate) fn sin_cos(x: &Ball, shift: usize) -> (Ball, Ball) {
+    let scale = x.scale;
+    let log_x = x.upper() - shift as f64;
+    let count = term_count(scale + 2, |k| (2 * k) as f64 * log_x - log_factorial(2 * k));
+    let denominator = shl_int(&BigInt::from(1), scale + shift);
+    let square = -&(&x.center * &x.center);
+    let square_denominator = &denominator * &denominator;
+    let sin = sum(
+        count,
+        |k| match k {
+            0 => Term::ratio(x.center.clone(), denominator.clone()),
+            _ => Term::ratio(
+                square.clone(),
+                &BigInt::from((2 * k) * (2 * k + 1)) * &square_denominator,
+            ),
+        },
+        scale,
+    );
+    let cos = sum(
+        count,
+        |k| match k {
+            0 => Term::ratio(BigInt::from(1), BigInt::from(1)),
+            _ => Term::ratio(
+                square.clone(),
+                &BigInt::from((2 * k - 1) * (2 * k)) * &square_denominator,
+            ),
+        },
+        scale,
+    );
+
+    // sin' and cos' are at most 1
+    let error = x.radius - shift as f64;
+    (sin.widened(error), cos.widened(error))
+}
+
+/// atan(x) for |x| ≤ 1/2, or atanh(x) if hyperbolic
+pub(crate) fn atan(x: &Ball, hyperbolic: bool) -> Ball {
+    let scale = x.scale;
+    let log_x = x.upper();
+    let count = term_count(scale + 3, |k| (2 * k + 1) as f64 * log_x);
+    let denominator = shl_int(&BigInt::from(1), scale);
+    let square = &x.center * &x.center;
+    let square = if hyperbolic { square } else { -&square };
+    let square_denominator = &denominator * &denominator;
+    let sum = sum(
+        count,
+        |k| match k {
+            0 => Term::ratio(x.center.clone(), denominator.clone()),
+            _ => Term {
+                a: BigInt::from(1),
+                b: BigInt::from(2 * k + 1),
+                p: square.clone(),
+                q: square_denominator.clone(),
+            },
+        },
+        scale,
+    );
+
+    // The derivatives are at most 1 / (1 - x^2) ≤ 4/3
+    sum.widened(x.radius + 0.5)
+}
+
+/// atanh(1 / m) at the scale
+fn atanh_recip(m: u64, scale: usize) -> Ball {
+    let log_x = -(m as f64).log2();
+    let count = term_count(scale + 3, |k| (2 * k + 1) as f64 * log_x);
+    let square = BigInt::from(m) * BigInt::from(m);
+    sum(
+        count,
+        |k| match k {
+            0 => Term::ratio(BigInt::from(1), BigInt::from(m)),
+            _ => Term {
+                a: BigInt::from(1),
+                b: BigInt::from(2 * k + 1),
+                p: BigInt::from(1),
+                q: square.clone(),
+            },
+        },
+        scale,
+    )
+}
+
+/// ln 2 = 18 atanh(1/26) - 2 atanh(1/4801) + 8 atanh(1/8749)
+pub(crate) fn ln2(scale: usize) -> Ball {
+    let terms = [(26, 18), (4801, -2), (8749, 8)];
+    terms
+        .iter()
+        .map(|&(m, factor)| atanh_recip(m, scale + 8).mul_int(&BigInt::from(factor)))
+        .reduce(|acc, term| acc.add(&term))
+        .unwrap()
+        .rescaled(8)
+}
+
+/// π = 426880 sqrt(10005) / sum_k (6k)! (13591409 + 545140134 k) / ((3k)! k!^3 (-640320)^3k)
+// see. Chudnovsky and Chudnovsky, Approximations and complex multiplication according to
+// Ramanujan (1988)
+pub(crate) fn pi(scale: usize) -> Ball {
+    let working = scale + 16;
+
+    // Each term adds more than 47 bits
+    let count = (working / 47 + 2) as u64;
+    let sum = sum(
+        count,
+        |k| match k {
+            0 => Term {
+                a: BigInt::from(13591409),
+                ..Term::ratio(BigInt::from(1), BigInt::from(1))
+            },
+            _ => Term {
+                a: BigInt::from(13591409 + 545140134 * k),
+                b: BigInt::from(1),
+                p: -(BigInt::from(6 * k - 5) * BigInt::from(2 * k - 1) * BigInt::from(6 * k - 1)),
+                q: BigInt::from(k).pow(3) * BigInt::from(10939058860032000u64),
+            },
+        },
+        working,
+    );
+
+    let root = Ball::exact(shl_int(&BigInt::from(10005), working), working).sqrt();
+    root.mul_int(&BigInt::from(426880)).div(&sum).rescaled(16)
+}
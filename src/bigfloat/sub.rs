@@ -0,0 +1,27 @@
+use super::add::add;
+use crate::{BigFloat, RoundingMode};
+
+impl BigFloat {
+    /// self - rhs rounded to precision bits
+    pub fn sub_rounded(&self, rhs: &BigFloat, precision: usize, mode: RoundingMode) -> BigFloat {
+        add(self, rhs, true, precision, mode)
+    }
+}
+
+/// Rounded to nearest with the larger precision of the operands
+impl std::ops::Sub for &BigFloat {
+    type Output = BigFloat;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let precision = self.precision.max(rhs.precision);
+        self.sub_rounded(rhs, precision, RoundingMode::HalfEven)
+    }
+}
+
+impl std::ops::Sub for BigFloat {
+    type Output = BigFloat;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        (&self).sub(&rhs)
+    }
+}
@@ -0,0 +1,193 @@
+use super::ball::Ball;
+use super::exp::halvings;
+use super::series;
+use super::top;
+use super::ziv::{transcendental, Approximation};
+use crate::{APNum, BigFloat, BigInt, BigRational, RoundingMode};
+
+impl BigFloat {
+    transcendental! {
+        "Sine of self" sin sin_rounded sin_decimal => sin;
+        "Cosine of self" cos cos_rounded cos_decimal => cos;
+        "Tangent of self" tan tan_rounded tan_decimal => tan;
+        "Arctangent of self" atan atan_rounded atan_decimal => atan;
+    }
+
+    /// Angle of the point (x, self) in (-π; π] rounded to nearest with the larger precision of the
+    /// operands, zero for the origin
+    pub fn atan2(&self, x: &BigFloat) -> BigFloat {
+        let precision = self.precision.max(x.precision);
+        self.atan2_rounded(x, precision, RoundingMode::HalfEven)
+    }
+
+    /// Angle of the point (x, self) in (-π; π] rounded to precision bits
+    pub fn atan2_rounded(&self, x: &BigFloat, precision: usize, mode: RoundingMode) -> BigFloat {
+        super::ziv::rounded(precision, mode, |working| atan2(self, x, working))
+    }
+
+    /// Angle of the point (x, self) in (-π; π] rounded to the given number of significant decimal
+    /// digits
+    pub fn atan2_decimal(&self, x: &BigFloat, digits: usize, mode: RoundingMode) -> String {
+        super::ziv::decimal(digits, mode, |working| atan2(self, x, working))
+    }
+}
+
+/// Guard bits at the working precision, small results about 2^top need that many more bits
+fn guard(working: usize, top: i64) -> usize {
+    2 * halvings(working) + (-top).max(0) as usize + 16
+}
+
+/// (r, k mod 4) with x = k π/2 + r and |r| ≤ π/4 about, at the scale
+fn reduce(x: &BigFloat, scale: usize) -> (Ball, u32) {
+    if top(x) < 0 {
+        return (Ball::from_float(x, scale), 0);
+    }
+
+    // k has about top(x) bits, so has to have π/2
+    let extra = top(x) as usize + 8;
+    let working = scale + extra;
+    let half_pi = series::pi(working).shr(1);
+    let x = Ball::from_float(x, working);
+
+    // k = x / (π/2) rounded to nearest
+    let (quotient, remainder) = &x.center.natural / &half_pi.center.natural;
+    let quotient = if &remainder << 1 >= half_pi.center.natural {
+        &quotient + 1u8
+    } else {
+        quotient
+    };
+    let k = BigInt {
+        sign: x.center.sign.clone(),
+        natural: quotient,
+    }
+    .zero_normalized();
+
+    let low = k.natural.digits.first().map_or(0, |digit| digit & 3);
+    let quadrant = if k.is_negative() { (4 - low) & 3 } else { low };
+    let r = x.sub(&half_pi.mul_int(&k)).rescaled(extra);
+    (r, quadrant)
+}
+
+/// (sin r, cos r) for |r| ≤ 1
+fn sin_cos(r: &Ball) -> (Ball, Ball) {
+    let halvings = halvings(r.scale).max(1);
+    let one = Ball::one(r.scale);
+    let (mut sin, mut cos) = series::sin_cos(r, halvings);
+
+    // sin 2a = 2 sin a cos a, cos 2a = 1 - 2 sin^2 a
+    for _ in 0..halvings {
+        (sin, cos) = (sin.mul(&cos).shl(1), one.sub(&sin.mul(&sin).shl(1)));
+    }
+    (sin, cos)
+}
+
+fn sin(x: &BigFloat, working: usize) -> Approximation {
+    if x.is_zero() {
+        return Approximation::Exact(BigRational::zero());
+    }
+
+    let (r, quadrant) = reduce(x, working + guard(working, top(x)));
+    let (sin, cos) = sin_cos(&r);
+    match quadrant {
+        0 => sin,
+        1 => cos,
+        2 => sin.neg(),
+        _ => cos.neg(),
+    }
+    .approximation(0)
+}
+
+fn cos(x: &BigFloat, working: usize) -> Approximation {
+    if x.is_zero() {
+        return Approximation::Exact(BigRational::from(1));
+    }
+
+    let (r, quadrant) = reduce(x, working + guard(working, 0));
+    let (sin, cos) = sin_cos(&r);
+    match quadrant {
+        0 => cos,
+        1 => sin.neg(),
+        2 => cos.neg(),
+        _ => sin,
+    }
+    .approximation(0)
+}
+
+fn tan(x: &BigFloat, working: usize) -> Approximation {
+    if x.is_zero() {
+        return Approximation::Exact(BigRational::zero());
+    }
+
+    let (r, quadrant) = reduce(x, working + guard(working, top(x)));
+    let (sin, cos) = sin_cos(&r);
+    match quadrant % 2 {
+        0 => sin.div(&cos),
+        _ => cos.div(&sin).neg(),
+    }
+    .approximation(0)
+}
+
+/// atan u for u ≥ 0
+fn atan_ball(u: &Ball) -> Ball {
+    let scale = u.scale;
+    let one = Ball::one(scale);
+
+    // atan u = π/2 - atan(1 / u)
+    if u.center > one.center {
+        return series::pi(scale).shr(1).sub(&atan_ball(&one.div(u)));
+    }
+
+    // atan u = 2 atan(u / (1 + sqrt(1 + u^2)))
+    let halvings = halvings(scale).max(1);
+    let mut v = u.clone();
+    for _ in 0..halvings {
+        v = v.div(&one.add(&one.add(&v.mul(&v)).sqrt()));
+    }
+    series::atan(&v, false).shl(halvings)
+}
+
+fn atan(x: &BigFloat, working: usize) -> Approximation {
+    if x.is_zero() {
+        return Approximation::Exact(BigRational::zero());
+    }
+
+    let scale = working + guard(working, top(x));
+    let result = atan_ball(&Ball::from_float(&x.abs(), scale));
+    let result = if x.is_negative() {
+        result.neg()
+    } else {
+        result
+    };
+    result.approximation(0)
+}
+
+fn atan2(y: &BigFloat, x: &BigFloat, working: usize) -> Approximation {
+    if y.is_zero() && !x.is_negative() {
+        return Approximation::Exact(BigRational::zero());
+    }
+
+    // About |y / x| for small angles
+    let scale = working + guard(working, top(y) - top(x) - 1);
+    let pi = series::pi(scale);
+    let result = if y.is_zero() {
+        pi
+    } else if x.is_zero() {
+        pi.shr(1)
+    } else {
+        // u = |y / x| to a few more bits than the scale, the ball is widened by the rounding error
+        let u = y.abs().div_rounded(&x.abs(), scale + 8, RoundingMode::Down);
+        let angle = atan_ball(&Ball::from_float(&u, scale).widened((top(&u) - 8) as f64));
+        if x.is_negative() {
+            pi.sub(&angle)
+        } else {
+            angle
+        }
+    };
+
+    let result = if y.is_negative() {
+        result.neg()
+    } else {
+        result
+    };
+    result.approximation(0)
+}
@@ -0,0 +1,125 @@
+// Correct rounding of the transcendental functions: the function is approximated with a known
+// error bound at increasing working precisions, until both ends of the error interval round to the
+// same result.
+// see. Ziv, Fast evaluation of elementary mathematical functions with correctly rounded last bit (1991)
+
+use std::f64::consts::LOG2_10;
+
+use super::{decimal_string, top};
+use crate::{APNum, BigFloat, BigInt, BigRational, RoundingMode};
+
+pub(crate) enum Approximation {
+    /// The exact value of the function, for the arguments where it is rational
+    Exact(BigRational),
+    /// The exact value of the function as a float, for exact values with large exponents
+    ExactFloat(BigFloat),
+    /// A value within 2^error of the one of the function
+    Inexact(BigFloat, i64),
+    /// Nothing useful at this working precision
+    Unknown,
+}
+
+/// The smallest and the largest value within 2^error of value
+fn bounds(value: &BigFloat, error: i64) -> (BigFloat, BigFloat) {
+    let epsilon = BigFloat {
+        mantissa: BigInt::from(1),
+        exponent: error,
+        precision: 1,
+    };
+
+    // Rounding outward keeps the interval, enough bits to make it tight
+    let precision = (top(value).max(error) - error) as usize + 2;
+    (
+        value.sub_rounded(&epsilon, precision, RoundingMode::Floor),
+        value.add_rounded(&epsilon, precision, RoundingMode::Ceiling),
+    )
+}
+
+/// Rounds a function value to precision bits, approximate gives it at a working precision in bits
+pub(crate) fn rounded(
+    precision: usize,
+    mode: RoundingMode,
+    approximate: impl Fn(usize) -> Approximation,
+) -> BigFloat {
+    let mut working = precision + 32;
+    loop {
+        match approximate(working) {
+            Approximation::Exact(value) => {
+                return BigFloat::from_rational(&value, precision, mode);
+            }
+            Approximation::ExactFloat(value) => return value.with_precision(precision, mode),
+            Approximation::Inexact(value, error) => {
+                let (low, high) = bounds(&value, error);
+                let low = low.with_precision(precision, mode);
+                if low == high.with_precision(precision, mode) {
+                    return low;
+                }
+            }
+            Approximation::Unknown => (),
+        }
+        working += working / 2;
+    }
+}
+
+/// Rounds a function value to the given number of significant decimal digits, approximate gives
+/// it at a working precision in bits
+pub(crate) fn decimal(
+    digits: usize,
+    mode: RoundingMode,
+    approximate: impl Fn(usize) -> Approximation,
+) -> String {
+    if digits == 0 {
+        panic!("Zero significant digits");
+    }
+
+    let mut working = (digits as f64 * LOG2_10).ceil() as usize + 32;
+    loop {
+        match approximate(working) {
+            Approximation::Exact(value) if value.is_zero() => return "0".to_string(),
+            Approximation::Exact(value) => {
+                return decimal_string(
+                    value.is_negative(),
+                    &value.numerator.natural,
+                    &value.denominator,
+                    digits,
+                    mode,
+                );
+            }
+            Approximation::ExactFloat(value) => return value.to_decimal(digits, mode),
+            Approximation::Inexact(value, error) => {
+                let (low, high) = bounds(&value, error);
+                if !low.is_zero() && low.is_negative() == high.is_negative() && !high.is_zero() {
+                    let low = low.to_decimal(digits, mode);
+                    if low == high.to_decimal(digits, mode) {
+                        return low;
+                    }
+                }
+            }
+            Approximation::Unknown => (),
+        }
+        working += working / 2;
+    }
+}
+
+/// Methods of BigFloat for functions of one argument: rounded to nearest with the precision of
+/// self, rounded to precision bits, and rounded to significant decimal digits
+macro_rules! transcendental {
+    ($($doc:literal $name:ident $rounded:ident $decimal:ident => $approximate:path;)*) => ($(
+        #[doc = concat!($doc, " rounded to nearest with the precision of self")]
+        pub fn $name(&self) -> BigFloat {
+            self.$rounded(self.precision, RoundingMode::HalfEven)
+        }
+
+        #[doc = concat!($doc, " rounded to precision bits")]
+        pub fn $rounded(&self, precision: usize, mode: RoundingMode) -> BigFloat {
+            $crate::bigfloat::ziv::rounded(precision, mode, |working| $approximate(self, working))
+        }
+
+        #[doc = concat!($doc, " rounded to the given number of significant decimal digits")]
+        pub fn $decimal(&self, digits: usize, mode: RoundingMode) -> String {
+            $crate::bigfloat::ziv::decimal(digits, mode, |working| $approximate(self, working))
+        }
+    )*)
+}
+
+pub(crate) use transcendental;
@@ -12,6 +12,8 @@ impl std::ops::Div for &BigInt {
         match (&self.sign, &rhs.sign) {
             (Positive, Positive) => (q, r),
             (Negative, Negative) => (q, -r),
+            // Exact division, nothing to round down
+            (Positive | Negative, _) if r.is_zero() => (-q, r),
             (Positive, Negative) => (-q - BigInt::from(1), rhs + &r),
             (Negative, Positive) => (-q - BigInt::from(1), rhs - &r),
             (Zero, _) => (BigInt::zero(), BigInt::zero()),
@@ -42,6 +44,8 @@ macro_rules! impl_digit_size_division {
         
                 match &self.sign {
                     Positive => (q, r),
+                    // Exact division, nothing to round down
+                    Negative if r == 0 => (-q, 0),
                     Negative => (-q - BigInt::from(1), rhs - r),
                     Zero => (BigInt::zero(), 0),
                 }
@@ -67,6 +71,8 @@ macro_rules! impl_digit_size_division {
                 match (&self.sign, &rhs.signum()) {
                     (Positive, 1) => (q, r as $sty),
                     (Negative, -1) => (q, -(r as $sty)),
+                    // Exact division, nothing to round down
+                    (Positive | Negative, _) if r == 0 => (-q, 0),
                     (Positive, -1) => (-q - BigInt::from(1), rhs + (r as $sty)),
                     (Negative, 1) => (-q - BigInt::from(1), rhs - (r as $sty)),
                     (Zero, _) => (BigInt::zero(), 0),
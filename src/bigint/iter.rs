@@ -0,0 +1,65 @@
+use std::borrow::Borrow;
+
+use crate::bignat::add::add_assign_digits;
+use crate::{APNum, BigInt, BigNat, Sign};
+
+/// Sum of the positive and of the negative terms in two buffers, then their difference
+fn sum(iter: impl Iterator<Item = impl Borrow<BigInt>>) -> BigInt {
+    let (mut positive, mut negative) = (BigNat::zero(), BigNat::zero());
+    for term in iter {
+        let term = term.borrow();
+        match term.sign {
+            Sign::Positive => add_assign_digits(&mut positive.digits, &term.natural.digits, 0),
+            Sign::Negative => add_assign_digits(&mut negative.digits, &term.natural.digits, 0),
+            Sign::Zero => (),
+        }
+    }
+    &positive - &negative
+}
+
+/// Product of the magnitudes by a balanced product tree, negative for an odd number of negative factors
+fn product(iter: impl Iterator<Item = BigInt>) -> BigInt {
+    let mut negative = false;
+    let mut factors = vec![];
+    for factor in iter {
+        match factor.sign {
+            Sign::Zero => return BigInt::zero(),
+            Sign::Negative => negative = !negative,
+            Sign::Positive => (),
+        }
+        factors.push(factor.natural);
+    }
+
+    BigInt {
+        sign: if negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        },
+        natural: BigNat::product(factors),
+    }
+}
+
+impl std::iter::Sum for BigInt {
+    fn sum<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        sum(iter)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a BigInt> for BigInt {
+    fn sum<I: Iterator<Item = &'a BigInt>>(iter: I) -> Self {
+        sum(iter)
+    }
+}
+
+impl std::iter::Product for BigInt {
+    fn product<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        product(iter)
+    }
+}
+
+impl<'a> std::iter::Product<&'a BigInt> for BigInt {
+    fn product<I: Iterator<Item = &'a BigInt>>(iter: I) -> Self {
+        product(iter.cloned())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{BigInt, BigNat};
+
+impl BigInt {
+    /// Floor of the base 2 logarithm
+    pub fn ilog2(&self) -> usize {
+        self.checked_ilog2()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Floor of the base 10 logarithm
+    pub fn ilog10(&self) -> usize {
+        self.checked_ilog10()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Floor of the logarithm in the given base
+    pub fn ilog(&self, base: &BigNat) -> usize {
+        if !self.is_positive() {
+            panic!("argument of integer logarithm must be positive")
+        }
+
+        self.natural.ilog(base)
+    }
+
+    pub fn checked_ilog2(&self) -> Option<usize> {
+        self.positive_natural()?.checked_ilog2()
+    }
+
+    pub fn checked_ilog10(&self) -> Option<usize> {
+        self.positive_natural()?.checked_ilog10()
+    }
+
+    pub fn checked_ilog(&self, base: &BigNat) -> Option<usize> {
+        self.positive_natural()?.checked_ilog(base)
+    }
+
+    /// Number of digits in the decimal representation of the absolute value (1 for zero)
+    pub fn decimal_digit_count(&self) -> usize {
+        self.natural.decimal_digit_count()
+    }
+
+    fn positive_natural(&self) -> Option<&BigNat> {
+        self.is_positive().then_some(&self.natural)
+    }
+}
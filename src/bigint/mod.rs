@@ -4,6 +4,9 @@ pub mod sub;
 pub mod div;
 pub mod cmp;
 pub mod eq;
+pub mod root;
+pub mod log;
+pub mod iter;
 
 use crate::{APNum, APNumParseError, BigInt, BigNat, Sign};
 
@@ -16,6 +19,20 @@ impl BigInt {
         self.sign == Sign::Positive
     }
 
+    pub fn pow(&self, power: u32) -> Self {
+        let sign = match self.sign {
+            Sign::Negative if power % 2 == 1 => Sign::Negative,
+            _ if power == 0 => Sign::Positive,
+            Sign::Zero => Sign::Zero,
+            _ => Sign::Positive,
+        };
+
+        BigInt {
+            sign,
+            natural: self.natural.pow(power),
+        }
+    }
+
     pub fn abs(&self) -> Self {
         let mut result = self.clone();
         if self.is_negative() {
@@ -131,6 +148,10 @@ impl_from_integer!(usize u8 u16 u32 u64 ;
 
 impl From<BigNat> for BigInt {
     fn from(value: BigNat) -> Self {
+        if value.is_zero() {
+            return BigInt::zero();
+        }
+
         BigInt {
             sign: Sign::Positive,
             natural: value,
@@ -138,6 +159,17 @@ impl From<BigNat> for BigInt {
     }
 }
 
+impl TryFrom<BigInt> for BigNat {
+    type Error = ();
+
+    fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+        match value.sign {
+            Sign::Negative => Err(()),
+            _ => Ok(value.natural),
+        }
+    }
+}
+
 impl std::str::FromStr for BigInt {
     type Err = APNumParseError;
 
@@ -0,0 +1,26 @@
+use crate::{BigInt, Sign};
+
+impl BigInt {
+    /// Floor of the square root
+    pub fn sqrt(&self) -> BigInt {
+        self.nth_root(2)
+    }
+
+    /// Cube root, rounded toward zero
+    pub fn cbrt(&self) -> BigInt {
+        self.nth_root(3)
+    }
+
+    /// nth root, rounded toward zero, odd roots of negative numbers are negative
+    pub fn nth_root(&self, n: u32) -> BigInt {
+        if self.is_negative() && n.is_multiple_of(2) {
+            panic!("Even root of a negative number");
+        }
+
+        let natural = self.natural.nth_root(n);
+        match self.sign {
+            Sign::Negative => -BigInt::from(natural),
+            _ => BigInt::from(natural),
+        }
+    }
+}
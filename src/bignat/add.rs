@@ -34,6 +34,37 @@ impl std::ops::Add for &BigNat {
     }
 }
 
+/// x += y * BASE^offset, x grows as needed
+pub(crate) fn add_assign_digits(x: &mut Vec<BigDigit>, y: &[BigDigit], offset: usize) {
+    if x.len() < offset + y.len() {
+        x.resize(offset + y.len(), 0);
+    }
+
+    let mut carry = 0;
+    for (position, right_digit) in y.iter().enumerate() {
+        let left_digit = &mut x[offset + position];
+        let digit_sum =
+            *left_digit as BiggerDigit + *right_digit as BiggerDigit + carry as BiggerDigit;
+        carry = (digit_sum / BASE) as BigDigit;
+        *left_digit = (digit_sum % BASE) as BigDigit;
+    }
+
+    // Propagate the carry
+    for left_digit in &mut x[offset + y.len()..] {
+        if carry == 0 {
+            return;
+        }
+
+        let digit_sum = *left_digit as BiggerDigit + carry as BiggerDigit;
+        carry = (digit_sum / BASE) as BigDigit;
+        *left_digit = (digit_sum % BASE) as BigDigit;
+    }
+
+    if carry > 0 {
+        x.push(carry);
+    }
+}
+
 impl std::ops::Add for BigNat {
     type Output = BigNat;
 
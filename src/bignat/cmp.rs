@@ -31,6 +31,10 @@ macro_rules! cmp_digit_size {
         pub fn $cmp_func(&self, other: $ty) -> std::cmp::Ordering {
             use std::cmp::Ordering::*;
     
+            if self.is_zero() {
+                return (0 as $ty).cmp(&other);
+            }
+
             if let ord @ (Greater | Less) = self.digit_count().cmp(&1) {
                 return ord;
             }
@@ -0,0 +1,94 @@
+use super::sieve::sieve;
+use crate::{BigDigit, BigNat};
+
+impl BigNat {
+    /// n!, by Luschny's prime swing algorithm, n! = (n/2)!^2 * n≀
+    // see. Luschny, Fast Factorial Functions (http://www.luschny.de/math/factorial/FastFactorialFunctions.htm)
+    pub fn factorial(n: u32) -> BigNat {
+        let primes = sieve(n);
+        factorial(n, &primes)
+    }
+
+    /// n!! = n * (n - 2) * (n - 4) * ... (0!! = 1)
+    pub fn double_factorial(n: u32) -> BigNat {
+        let k = n / 2;
+        if n.is_multiple_of(2) {
+            // (2k)!! = 2^k * k!
+            return BigNat::factorial(k) << k as usize;
+        }
+
+        // n! = n!! * (n - 1)!! = n!! * 2^k * k!, so v_p(n!!) = v_p(n!) - v_p(k!) for odd p
+        from_prime_exponents(n, |p| {
+            if p == 2 {
+                0
+            } else {
+                legendre_exponent(n, p) - legendre_exponent(k, p)
+            }
+        })
+    }
+
+    /// Binomial coefficient, n choose k (0 for k > n)
+    pub fn binomial(n: u32, k: u32) -> BigNat {
+        if k > n {
+            return BigNat::from(0usize);
+        }
+
+        from_prime_exponents(n, |p| {
+            legendre_exponent(n, p) - legendre_exponent(k, p) - legendre_exponent(n - k, p)
+        })
+    }
+
+    /// Multinomial coefficient, (k_1 + ... + k_m)! / (k_1! * ... * k_m!)
+    pub fn multinomial(ks: &[u32]) -> BigNat {
+        let n = ks
+            .iter()
+            .try_fold(0u32, |sum, k| sum.checked_add(*k))
+            .expect("sum of the multinomial arguments overflows u32");
+
+        from_prime_exponents(n, |p| {
+            legendre_exponent(n, p) - ks.iter().map(|k| legendre_exponent(*k, p)).sum::<u32>()
+        })
+    }
+}
+
+fn factorial(n: u32, primes: &[BigDigit]) -> BigNat {
+    if n < 2 {
+        return BigNat::from(1usize);
+    }
+
+    let half = factorial(n / 2, primes);
+    &(&half * &half) * &swing(n, primes)
+}
+
+/// Swinging factorial n≀ = n! / (n/2)!^2
+fn swing(n: u32, primes: &[BigDigit]) -> BigNat {
+    // The exponent of p in n≀ is the number of odd floor(n / p^i), i ≥ 1
+    BigNat::product(primes.iter().take_while(|p| **p <= n).filter_map(|p| {
+        let mut exponent = 0;
+        let mut q = n;
+        while q >= *p {
+            q /= p;
+            exponent += q & 1;
+        }
+        (exponent > 0).then(|| BigNat::from(*p).pow(exponent))
+    }))
+}
+
+/// Exponent of p in n!, v_p(n!) = Σ floor(n / p^i), i ≥ 1
+fn legendre_exponent(n: u32, p: BigDigit) -> u32 {
+    let mut exponent = 0;
+    let mut q = n;
+    while q >= p {
+        q /= p;
+        exponent += q;
+    }
+    exponent
+}
+
+/// Product of p^exponent(p) over the primes p up to limit
+fn from_prime_exponents(limit: u32, exponent: impl Fn(BigDigit) -> u32) -> BigNat {
+    BigNat::product(sieve(limit).into_iter().filter_map(|p| {
+        let exponent = exponent(p);
+        (exponent > 0).then(|| BigNat::from(p).pow(exponent))
+    }))
+}
@@ -7,6 +7,10 @@ impl std::ops::Div for &BigNat {
     fn div(self, rhs: Self) -> Self::Output {
         use std::cmp::Ordering::*;
 
+        if rhs.is_zero() {
+            panic!("Division by Zero");
+        }
+
         // Short-circuit
         if rhs == &BigNat::from(1usize) {
             return (self.clone(), BigNat::zero());
@@ -18,19 +22,16 @@ impl std::ops::Div for &BigNat {
             Greater => (self.clone(), rhs.clone()),
         };
 
-        if v.is_zero() {
-            panic!("Division by Zero");
-        }
-
         let n = v.digit_count();
         let m = u.digit_count() - n;
 
         // D1 [Normalize.]
-        // 2^32 - 1 / [1; (2^32 - 1)] = [1; 2^32 - 1] ⊂ u32
-        let d = BigNat::from((BASE - 1) as BigDigit / v.digits[n - 1]);
+        // d is a power of two which sets the most significant bit of v's leading digit,
+        // so v * d has still n digits and u * d fits into the extra (zero) digit.
+        let d: BigDigit = 1 << v.digits[n - 1].leading_zeros();
         u.digits.push(0);
-        u = &u * &d;
-        v = &v * &d;
+        u = &u * d;
+        v = &v * d;
 
         // D2 [Initialize j.]
         let mut q = BigNat::zero();
@@ -50,7 +51,7 @@ impl std::ops::Div for &BigNat {
             let mut rh = (f * BASE + s) % v.digits[n - 1] as BiggerDigit;
 
             loop {
-                if qh == BASE
+                if qh >= BASE
                     || (n > 1
                         && BigNat::from(qh) * BigNat::from(v.digits[n - 2])
                             > BigNat::from(BASE) * BigNat::from(rh)
@@ -67,14 +68,14 @@ impl std::ops::Div for &BigNat {
             }
 
             // D4 [Multiply and subtract.]
-            let mut mul_and_sub = BigNat::from(&u.digits[ju..=ju + n]) - &BigNat::from(qh) * &v;
+            // The slice may have leading zeros, which would confuse the length based comparison
+            // of the subtraction.
+            let mut mul_and_sub = BigNat::from(&u.digits[ju..=ju + n]).zero_normalized()
+                - &BigNat::from(qh) * &v;
 
             // D5 [Test remainder.]
-            // Originally in the algorithm, it's just a check rather than a loop,
-            // but there are some cases where qh is off by more than one (see tests).
-            // Beacuse that I don't totally understand what's going on the alogrithm,
-            // so I can't tell what's wrong.
-            while mul_and_sub.is_negative() {
+            // After the normalization at D1 and the test at D3, qh is at most one too large.
+            if mul_and_sub.is_negative() {
                 qh -= 1;
 
                 // D6 [Add back.]
@@ -97,7 +98,7 @@ impl std::ops::Div for &BigNat {
         let (r, rr) = BigNat::from(&u.digits[0..=n - 1]).zero_normalized() / d;
 
         // rr has to be zero, because it's multliplied by d at D1 [Normalize.]
-        debug_assert!(rr.is_zero());
+        debug_assert!(rr == 0);
 
         q.digits.reverse();
         (q.zero_normalized(), r)
@@ -122,21 +123,22 @@ macro_rules! impl_digit_size_division {
             fn div(self, rhs: $ty) -> Self::Output {
                 use std::cmp::Ordering::*;
 
+                // Short-circuit
+                if rhs == 0 {
+                    panic!("Division by Zero");
+                }
+
                 // Short-circuit
                 if rhs == 1 {
                     return (self.clone(), 0);
                 }
 
                 let (u, v) = match self.$cmp_func(rhs) {
-                    Less => return (BigNat::zero(), self.digits[0] as $ty),
+                    Less => return (BigNat::zero(), *self.digits.first().unwrap_or(&0) as $ty),
                     Equal => return (BigNat::from(1usize), 0),
                     Greater => (self.clone(), rhs.clone()),
                 };
 
-                if v == 0 {
-                    panic!("Division by Zero");
-                }
-
                 // S1
                 let mut w = BigNat::zero();
                 let n = u.digit_count();
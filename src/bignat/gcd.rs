@@ -0,0 +1,47 @@
+use crate::{APNum, BigInt, BigNat, Sign};
+
+impl BigNat {
+    /// Greatest common divisor (gcd(0, 0) = 0)
+    // see. Knuth, The Art Of Computer Programming Vol. 2 Section 4.5.2, Algorithm A
+    pub fn gcd(&self, other: &BigNat) -> BigNat {
+        let (mut u, mut v) = (self.clone(), other.clone());
+        while !v.is_zero() {
+            let (_, r) = &u / &v;
+            (u, v) = (v, r);
+        }
+        u
+    }
+
+    /// x with self * x ≡ 1 (mod modulus), None if they aren't coprime
+    // see. Knuth, The Art Of Computer Programming Vol. 2 Section 4.5.2, Algorithm X
+    pub fn mod_inverse(&self, modulus: &BigNat) -> Option<BigNat> {
+        let (_, remainder) = self / modulus;
+        let (mut u, mut v) = (modulus.clone(), remainder);
+        // Coefficients of self, u ≡ self * s (mod modulus)
+        let (mut s, mut t) = (BigInt::zero(), BigInt::from(1));
+        while !v.is_zero() {
+            let (q, r) = &u / &v;
+            (u, v) = (v, r);
+            (s, t) = (t.clone(), &s - &(&BigInt::from(q) * &t));
+        }
+
+        if !u.eq_u8(1) {
+            return None;
+        }
+
+        Some(match s.sign {
+            Sign::Negative => (modulus - &s.natural).natural,
+            _ => s.natural,
+        })
+    }
+
+    /// Least common multiple (0 if any of them is 0)
+    pub fn lcm(&self, other: &BigNat) -> BigNat {
+        if self.is_zero() || other.is_zero() {
+            return BigNat::zero();
+        }
+
+        let (quotient, _) = self / &self.gcd(other);
+        &quotient * other
+    }
+}
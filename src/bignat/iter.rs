@@ -0,0 +1,37 @@
+use std::borrow::Borrow;
+
+use super::add::add_assign_digits;
+use crate::{APNum, BigNat};
+
+/// Sum of the terms, added into a single buffer
+fn sum(iter: impl Iterator<Item = impl Borrow<BigNat>>) -> BigNat {
+    let mut sum = BigNat::zero();
+    for term in iter {
+        add_assign_digits(&mut sum.digits, &term.borrow().digits, 0);
+    }
+    sum
+}
+
+impl std::iter::Sum for BigNat {
+    fn sum<I: Iterator<Item = BigNat>>(iter: I) -> Self {
+        sum(iter)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a BigNat> for BigNat {
+    fn sum<I: Iterator<Item = &'a BigNat>>(iter: I) -> Self {
+        sum(iter)
+    }
+}
+
+impl std::iter::Product for BigNat {
+    fn product<I: Iterator<Item = BigNat>>(iter: I) -> Self {
+        BigNat::product(iter)
+    }
+}
+
+impl<'a> std::iter::Product<&'a BigNat> for BigNat {
+    fn product<I: Iterator<Item = &'a BigNat>>(iter: I) -> Self {
+        BigNat::product(iter.cloned())
+    }
+}
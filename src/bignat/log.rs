@@ -0,0 +1,76 @@
+use crate::{APNum, BigDigit, BigNat};
+
+impl BigNat {
+    /// Floor of the base 2 logarithm
+    pub fn ilog2(&self) -> usize {
+        self.checked_ilog2()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Floor of the base 10 logarithm
+    pub fn ilog10(&self) -> usize {
+        self.checked_ilog10()
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Floor of the logarithm in the given base
+    pub fn ilog(&self, base: &BigNat) -> usize {
+        if base.cmp_u8(2).is_lt() {
+            panic!("base of integer logarithm must be at least 2");
+        }
+
+        self.checked_ilog(base)
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    pub fn checked_ilog2(&self) -> Option<usize> {
+        self.bits().checked_sub(1)
+    }
+
+    pub fn checked_ilog10(&self) -> Option<usize> {
+        self.checked_ilog(&BigNat::from(10usize))
+    }
+
+    /// None for zero self or for a base smaller than 2
+    pub fn checked_ilog(&self, base: &BigNat) -> Option<usize> {
+        if self.is_zero() || base.cmp_u8(2).is_lt() {
+            return None;
+        }
+
+        // Estimate from the floating point logarithms, which is off by at most one
+        // in either direction, then correct it.
+        let estimate = (self.log2_approx() / base.log2_approx()).floor() as usize;
+        let mut log = estimate.saturating_sub(1);
+        let mut power = base.pow_uint(log);
+        while power > *self {
+            log -= 1;
+            power = (&power / base).0;
+        }
+
+        let mut next = &power * base;
+        while next <= *self {
+            log += 1;
+            next = &next * base;
+        }
+
+        Some(log)
+    }
+
+    /// Number of digits in the decimal representation (1 for zero)
+    pub fn decimal_digit_count(&self) -> usize {
+        self.checked_ilog10().map_or(1, |log| log + 1)
+    }
+
+    /// Base 2 logarithm from the two most significant digits
+    fn log2_approx(&self) -> f64 {
+        let count = self.digit_count();
+        let (high, low) = match count {
+            0 => return f64::NEG_INFINITY,
+            1 => (self.digits[0], 0),
+            _ => (self.digits[count - 1], self.digits[count - 2]),
+        };
+
+        let top = high as f64 * (BigDigit::MAX as f64 + 1.0) + low as f64;
+        top.log2() + (count.saturating_sub(2) * BigDigit::BITS as usize) as f64
+    }
+}
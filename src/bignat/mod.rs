@@ -4,23 +4,81 @@ pub mod sub;
 pub mod div;
 pub mod cmp;
 pub mod eq;
+pub mod root;
+pub mod log;
+pub mod shift;
+pub mod modpow;
+pub mod prime;
+pub mod sieve;
+pub mod gcd;
+pub mod sqrt_mod;
+pub mod combinatorics;
+pub mod tree;
+pub mod iter;
 
 use crate::{APNum, APNumParseError, BigNat, BASE, BigDigit};
 
 impl BigNat {    
+    /// Number of bits needed to represent the number (0 for zero)
+    pub fn bits(&self) -> usize {
+        match self.digits.last() {
+            Some(last) => {
+                (self.digit_count() - 1) * BigDigit::BITS as usize
+                    + (BigDigit::BITS - last.leading_zeros()) as usize
+            }
+            None => 0,
+        }
+    }
+
+    /// Whether the bit at the given index (from the least significant one) is set
+    pub fn bit(&self, index: usize) -> bool {
+        let digit = self.digits.get(index / BigDigit::BITS as usize).unwrap_or(&0);
+        digit >> (index % BigDigit::BITS as usize) & 1 == 1
+    }
+
+    /// Number of trailing zero bits (None for zero)
+    pub fn trailing_zeros(&self) -> Option<usize> {
+        let position = self.digits.iter().position(|digit| *digit != 0)?;
+        Some(position * BigDigit::BITS as usize + self.digits[position].trailing_zeros() as usize)
+    }
+
+    pub fn is_even(&self) -> bool {
+        !self.bit(0)
+    }
+
+    pub fn pow(&self, power: u32) -> BigNat {
+        self.pow_uint(power as usize)
+    }
+
+    fn power_of_two(power: usize) -> BigNat {
+        let mut digits = vec![0; power / BigDigit::BITS as usize];
+        digits.push(1 << (power % BigDigit::BITS as usize));
+        BigNat { digits }
+    }
+
     fn pow_uint(&self, power: usize) -> BigNat {
         if power == 0 {
             return BigNat::from(1usize);
         }
 
+        // Square-and-multiply, scanning the bits of power from the most significant one
         let mut acc = BigNat::from(1usize);
-        for _ in 0..power {
-            acc = &acc * self;
+        for bit in (0..usize::BITS - power.leading_zeros()).rev() {
+            acc = &acc * &acc;
+            if power >> bit & 1 == 1 {
+                acc = &acc * self;
+            }
         }
         acc
     }
 }
 
+/// Digits without the leading zeros
+pub(crate) fn trim_digits(digits: &[BigDigit]) -> &[BigDigit] {
+    let length = digits.iter().rposition(|digit| *digit != 0).map_or(0, |last| last + 1);
+    &digits[..length]
+}
+
 impl APNum for BigNat {
     fn zero() -> Self {
         BigNat { digits: vec![] }
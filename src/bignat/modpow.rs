@@ -0,0 +1,21 @@
+use crate::BigNat;
+
+impl BigNat {
+    /// self^exponent mod modulus
+    pub fn modpow(&self, exponent: &BigNat, modulus: &BigNat) -> BigNat {
+        let (_, base) = self / modulus;
+
+        // 1 mod modulus, to cover the modulus = 1 case
+        let (_, mut acc) = &BigNat::from(1usize) / modulus;
+
+        // Square-and-multiply, scanning the bits of exponent from the most significant one
+        for bit in (0..exponent.bits()).rev() {
+            (_, acc) = &(&acc * &acc) / modulus;
+            if exponent.bit(bit) {
+                (_, acc) = &(&acc * &base) / modulus;
+            }
+        }
+
+        acc
+    }
+}
@@ -1,4 +1,7 @@
 use crate::{BigNat, BiggerDigit, BASE, BigDigit, APNum};
+use super::add::add_assign_digits;
+use super::sub::sub_assign_digits;
+use super::trim_digits;
 
 impl std::ops::Mul for &BigNat {
     type Output = BigNat;
@@ -11,31 +14,86 @@ impl std::ops::Mul for &BigNat {
             return BigNat::zero();
         }
 
-        let mut result = BigNat::zero();
-        for (position, right_digit) in rhs.digits.iter().enumerate() {
-            let mut product = BigNat {
-                digits: vec![0; position],
-            };
-            let mut carry = 0;
-            for left_digit in &self.digits {
-                // digit_product ϵ [0; (2^32 - 1)*(2^32 - 1) + 4294967294] ⊂ u64
-                // (max. carry is 4294967294 by ((2^32 - 1) * (2^32 - 1)) / 2^32)
-                let digit_product =
-                    *left_digit as BiggerDigit * *right_digit as BiggerDigit + carry as BiggerDigit;
-                // carry ϵ [0; 4294967294] ⊂ u32
-                carry = (digit_product / BASE) as BigDigit;
-                // digit_product % BASE ϵ [0; (2^32 - 1)] ⊂ u32
-                product.digits.push((digit_product % BASE) as BigDigit);
-            }
+        BigNat {
+            digits: multiply_digits(&self.digits, &rhs.digits),
+        }
+        .zero_normalized()
+    }
+}
 
-            if carry > 0 {
-                product.digits.push(carry);
-            }
+// Below this many digits (of the shorter operand), schoolbook multiplication is faster
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Product of two digit sequences, which may have leading zeros
+fn multiply_digits(x: &[BigDigit], y: &[BigDigit]) -> Vec<BigDigit> {
+    let (x, y) = (trim_digits(x), trim_digits(y));
+    let (short, long) = if x.len() <= y.len() { (x, y) } else { (y, x) };
+
+    if short.is_empty() {
+        return vec![];
+    }
+
+    if short.len() < KARATSUBA_THRESHOLD {
+        return schoolbook(long, short);
+    }
 
-            result = result + product;
+    // Unbalanced operands, split the longer one into pieces of the shorter one's size
+    if long.len() >= 2 * short.len() {
+        let mut result = vec![];
+        for (index, piece) in long.chunks(short.len()).enumerate() {
+            add_assign_digits(&mut result, &multiply_digits(short, piece), index * short.len());
         }
-        result
+        return result;
     }
+
+    karatsuba(x, y)
+}
+
+fn schoolbook(x: &[BigDigit], y: &[BigDigit]) -> Vec<BigDigit> {
+    let mut result = vec![0; x.len() + y.len()];
+    for (position, right_digit) in y.iter().enumerate() {
+        let mut carry = 0;
+        for (offset, left_digit) in x.iter().enumerate() {
+            // digit_product ϵ [0; (2^32 - 1)*(2^32 - 1) + (2^32 - 1) + (2^32 - 1)] = [0; 2^64 - 1] = u64
+            // (previous digit of the result and the carry are added)
+            let digit_product = *left_digit as BiggerDigit * *right_digit as BiggerDigit
+                + result[position + offset] as BiggerDigit
+                + carry as BiggerDigit;
+            // carry ϵ [0; 2^32 - 1] = u32
+            carry = (digit_product / BASE) as BigDigit;
+            // digit_product % BASE ϵ [0; (2^32 - 1)] ⊂ u32
+            result[position + offset] = (digit_product % BASE) as BigDigit;
+        }
+        result[position + x.len()] = carry;
+    }
+    result
+}
+
+// see. Knuth, The Art Of Computer Programming Vol. 2 Section 4.3.3
+// x and y have close lengths (neither is twice as long as the other)
+fn karatsuba(x: &[BigDigit], y: &[BigDigit]) -> Vec<BigDigit> {
+    // x = x1 * BASE^half + x0, y = y1 * BASE^half + y0
+    let half = x.len().max(y.len()) / 2;
+    let (x0, x1) = x.split_at(half);
+    let (y0, y1) = y.split_at(half);
+
+    let z0 = multiply_digits(x0, y0);
+    let z2 = multiply_digits(x1, y1);
+
+    // z1 = (x0 + x1) * (y0 + y1) - z0 - z2 = x0 * y1 + x1 * y0
+    let mut x_sum = x0.to_vec();
+    add_assign_digits(&mut x_sum, x1, 0);
+    let mut y_sum = y0.to_vec();
+    add_assign_digits(&mut y_sum, y1, 0);
+    let mut z1 = multiply_digits(&x_sum, &y_sum);
+    sub_assign_digits(&mut z1, &z0);
+    sub_assign_digits(&mut z1, &z2);
+
+    // x * y = z2 * BASE^(2 * half) + z1 * BASE^half + z0
+    let mut result = z0;
+    add_assign_digits(&mut result, &z1, half);
+    add_assign_digits(&mut result, &z2, 2 * half);
+    result
 }
 
 impl std::ops::Mul for BigNat {
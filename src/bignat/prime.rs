@@ -0,0 +1,282 @@
+use crate::symbol::jacobi;
+use crate::{APNum, BigDigit, BigInt, BigNat};
+
+/// Primes below 1000, for trial division
+pub(crate) const SMALL_PRIMES: [BigDigit; 168] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83,
+    89, 97, 101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179,
+    181, 191, 193, 197, 199, 211, 223, 227, 229, 233, 239, 241, 251, 257, 263, 269, 271,
+    277, 281, 283, 293, 307, 311, 313, 317, 331, 337, 347, 349, 353, 359, 367, 373, 379,
+    383, 389, 397, 401, 409, 419, 421, 431, 433, 439, 443, 449, 457, 461, 463, 467, 479,
+    487, 491, 499, 503, 509, 521, 523, 541, 547, 557, 563, 569, 571, 577, 587, 593, 599,
+    601, 607, 613, 617, 619, 631, 641, 643, 647, 653, 659, 661, 673, 677, 683, 691, 701,
+    709, 719, 727, 733, 739, 743, 751, 757, 761, 769, 773, 787, 797, 809, 811, 821, 823,
+    827, 829, 839, 853, 857, 859, 863, 877, 881, 883, 887, 907, 911, 919, 929, 937, 941,
+    947, 953, 967, 971, 977, 983, 991, 997,
+];
+
+// Miller–Rabin with the first 13 primes as bases has no pseudoprimes below this bound.
+// see. Sorenson and Webster, Strong Pseudoprimes to Twelve Prime Bases
+const DETERMINISTIC_BOUND: &str = "3317044064679887385961981";
+const DETERMINISTIC_BASES: usize = 13;
+
+impl BigNat {
+    /// Primality test, trial division by the primes below 1000, then
+    ///   - below 3.3 * 10^24, Miller–Rabin with deterministic bases (the answer is exact),
+    ///   - otherwise, Baillie–PSW followed by `rounds` Miller–Rabin rounds with pseudo random bases.
+    ///
+    /// A false answer is always correct, and no Baillie–PSW pseudoprime is known.
+    pub fn is_probable_prime(&self, rounds: usize) -> bool {
+        if self.cmp_u8(2).is_lt() {
+            return false;
+        }
+
+        for prime in SMALL_PRIMES {
+            if self.eq_u32(prime) {
+                return true;
+            }
+
+            if (self / prime).1 == 0 {
+                return false;
+            }
+        }
+
+        // Composite numbers below 1000^2 have a factor below 1000
+        if self.cmp_u32(1_000_000).is_lt() {
+            return true;
+        }
+
+        if *self < BigNat::try_from(DETERMINISTIC_BOUND).unwrap() {
+            return SMALL_PRIMES[..DETERMINISTIC_BASES]
+                .iter()
+                .all(|base| self.miller_rabin(&BigNat::from(*base)));
+        }
+
+        // Baillie–PSW
+        if !self.miller_rabin(&BigNat::from(2usize)) || !self.strong_lucas() {
+            return false;
+        }
+
+        // Bases in [2; self - 2], drawn by xorshift seeded from the number itself
+        let mut state = self.digits[0] as u64 | (self.digits[1] as u64) << 32;
+        let range = (self - 3u8).natural;
+        (0..rounds).all(|_| {
+            let random = BigNat {
+                digits: (0..self.digit_count())
+                    .map(|_| {
+                        state ^= state << 13;
+                        state ^= state >> 7;
+                        state ^= state << 17;
+                        state as BigDigit
+                    })
+                    .collect(),
+            }
+            .zero_normalized();
+
+            self.miller_rabin(&((&random / &range).1 + 2u8))
+        })
+    }
+
+    /// Smallest prime bigger than self
+    pub fn next_prime(&self) -> BigNat {
+        if self.cmp_u8(2).is_lt() {
+            return BigNat::from(2usize);
+        }
+
+        // Step over odd candidates
+        let mut candidate = if self.is_even() { self + 1u8 } else { self + 2u8 };
+        while !candidate.is_probable_prime(0) {
+            candidate = candidate + 2u8;
+        }
+        candidate
+    }
+
+    /// Biggest prime smaller than self (None for self ≤ 2)
+    pub fn prev_prime(&self) -> Option<BigNat> {
+        if self.cmp_u8(2).is_le() {
+            return None;
+        } else if self.eq_u8(3) {
+            return Some(BigNat::from(2usize));
+        }
+
+        // Step over odd candidates
+        let step_back = |x: &BigNat, by: u8| BigNat::try_from(x - by).unwrap();
+        let mut candidate = if self.is_even() { step_back(self, 1) } else { step_back(self, 2) };
+        while !candidate.is_probable_prime(0) {
+            candidate = step_back(&candidate, 2);
+        }
+        Some(candidate)
+    }
+
+    /// Random prime with exactly the given number of bits, rng yields uniformly random digits
+    pub fn random_prime<R: FnMut() -> BigDigit>(bits: usize, rng: &mut R) -> BigNat {
+        if bits < 2 {
+            panic!("There are no primes with less than 2 bits");
+        }
+
+        // 2 is the only even prime, and one of the two primes with 2 bits
+        if bits == 2 && rng().is_multiple_of(2) {
+            return BigNat::from(2usize);
+        }
+
+        loop {
+            let candidate = BigNat::random_odd(bits, rng);
+            if candidate.is_probable_prime(0) {
+                return candidate;
+            }
+        }
+    }
+
+    /// Random safe prime p (where (p - 1) / 2 is also prime)
+    /// with exactly the given number of bits, rng yields uniformly random digits
+    pub fn random_safe_prime<R: FnMut() -> BigDigit>(bits: usize, rng: &mut R) -> BigNat {
+        if bits < 3 {
+            panic!("There are no safe primes with less than 3 bits");
+        }
+
+        // 5 = 2 * 2 + 1 is the only safe prime with an even (p - 1) / 2
+        if bits == 3 && rng().is_multiple_of(2) {
+            return BigNat::from(5usize);
+        }
+
+        loop {
+            let half = BigNat::random_odd(bits - 1, rng);
+            let candidate = &(&half << 1) + 1u8;
+            if candidate.is_probable_prime(0) && half.is_probable_prime(0) {
+                return candidate;
+            }
+        }
+    }
+
+    /// Random odd number with exactly the given number of bits (at least 1)
+    fn random_odd<R: FnMut() -> BigDigit>(bits: usize, rng: &mut R) -> BigNat {
+        let count = bits.div_ceil(BigDigit::BITS as usize);
+        let mut result = BigNat {
+            digits: (0..count).map(|_| rng()).collect(),
+        };
+
+        // Clear the bits above, then set the highest and the lowest ones
+        let top_bits = (bits - 1) % BigDigit::BITS as usize + 1;
+        result.digits[count - 1] &= BigDigit::MAX >> (BigDigit::BITS as usize - top_bits);
+        result.digits[count - 1] |= 1 << (top_bits - 1);
+        result.digits[0] |= 1;
+        result
+    }
+
+    // see. Knuth, The Art Of Computer Programming Vol. 2 Section 4.5.4, Algorithm P
+    // self is odd and bigger than base
+    fn miller_rabin(&self, base: &BigNat) -> bool {
+        let n_minus_one = (self - 1u8).natural;
+
+        // self - 1 = 2^k * q, q odd
+        let k = n_minus_one.trailing_zeros().unwrap();
+        let q = &n_minus_one >> k;
+
+        let mut y = base.modpow(&q, self);
+        if y.eq_u8(1) || y == n_minus_one {
+            return true;
+        }
+
+        for _ in 1..k {
+            (_, y) = &(&y * &y) / self;
+            if y == n_minus_one {
+                return true;
+            } else if y.eq_u8(1) {
+                return false;
+            }
+        }
+
+        false
+    }
+
+    // see. Baillie and Wagstaff, Lucas Pseudoprimes, Section 3 (Selfridge's Method A with P = 1)
+    // self is odd, bigger than 1000 and has no small factors
+    fn strong_lucas(&self) -> bool {
+        // Otherwise there is no D with (D/n) = -1
+        if self.is_perfect_square() {
+            return false;
+        }
+
+        // First D in 5, -7, 9, -11, ... with (D/n) = -1
+        let mut d: i64 = 5;
+        loop {
+            match jacobi(&BigInt::from(d), self) {
+                -1 => break,
+                // self > |D|, so they have a common factor
+                0 => return false,
+                _ => d = if d > 0 { -(d + 2) } else { -d + 2 },
+            }
+        }
+
+        let d_residue = self.residue(d);
+        let q_residue = self.residue((1 - d) / 4);
+
+        let reduce = |x: BigNat| (&x / self).1;
+        let sub = |x: &BigNat, y: &BigNat| {
+            if x >= y {
+                (x - y).natural
+            } else {
+                (&(x + self) - y).natural
+            }
+        };
+        // x / 2 mod self, self is odd
+        let half = |x: BigNat| {
+            if x.is_even() {
+                x >> 1
+            } else {
+                (&x + self) >> 1
+            }
+        };
+
+        // self + 1 = 2^s * k, k odd
+        let n_plus_one = self + 1u8;
+        let s = n_plus_one.trailing_zeros().unwrap();
+        let k = &n_plus_one >> s;
+
+        // U_1 = 1, V_1 = P = 1
+        let mut u = BigNat::from(1usize);
+        let mut v = BigNat::from(1usize);
+        let mut qk = q_residue.clone();
+        for bit in (0..k.bits() - 1).rev() {
+            // U_2j = U_j * V_j, V_2j = V_j^2 - 2Q^j
+            u = reduce(&u * &v);
+            v = sub(&reduce(&v * &v), &reduce(&qk * 2u8));
+            qk = reduce(&qk * &qk);
+
+            if k.bit(bit) {
+                // U_(j+1) = (P * U_j + V_j) / 2, V_(j+1) = (D * U_j + P * V_j) / 2
+                (u, v) = (
+                    half(reduce(&u + &v)),
+                    half(reduce(&(&d_residue * &u) + &v)),
+                );
+                qk = reduce(&qk * &q_residue);
+            }
+        }
+
+        // Strong Lucas probable prime if U_k = 0 or V_(k * 2^r) = 0 for some 0 ≤ r < s
+        if u.is_zero() {
+            return true;
+        }
+
+        for _ in 0..s {
+            if v.is_zero() {
+                return true;
+            }
+
+            v = sub(&reduce(&v * &v), &reduce(&qk * 2u8));
+            qk = reduce(&qk * &qk);
+        }
+
+        false
+    }
+
+    /// value mod self, |value| < self
+    fn residue(&self, value: i64) -> BigNat {
+        let magnitude = BigNat::from(value.unsigned_abs());
+        if value < 0 {
+            (self - &magnitude).natural
+        } else {
+            magnitude
+        }
+    }
+}
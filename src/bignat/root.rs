@@ -0,0 +1,75 @@
+use crate::{APNum, BigNat};
+
+impl BigNat {
+    /// Floor of the square root
+    pub fn sqrt(&self) -> BigNat {
+        self.nth_root(2)
+    }
+
+    /// Floor of the square root and the remainder, self = s^2 + r
+    pub fn sqrt_rem(&self) -> (BigNat, BigNat) {
+        let s = self.sqrt();
+        let r = (self - &(&s * &s)).natural;
+        (s, r)
+    }
+
+    /// Floor of the cube root
+    pub fn cbrt(&self) -> BigNat {
+        self.nth_root(3)
+    }
+
+    /// Floor of the nth root
+    pub fn nth_root(&self, n: u32) -> BigNat {
+        if n == 0 {
+            panic!("Zeroth root");
+        }
+
+        // Short-circuit
+        if n == 1 || self.is_zero() {
+            return self.clone();
+        }
+
+        // Every number in [1; 2^n) has 1 as its root
+        let bits = self.bits();
+        if bits <= n as usize {
+            return BigNat::from(1usize);
+        }
+
+        // Newton iteration, x_{k+1} = ((n - 1) * x_k + self / x_k^(n - 1)) / n
+        // Starting from above (2^ceil(bits / n) > root), the sequence decreases
+        // strictly until it reaches the floor of the root.
+        let mut x = BigNat::power_of_two(bits.div_ceil(n as usize));
+        loop {
+            let (quotient, _) = self / &x.pow_uint(n as usize - 1);
+            let (y, _) = (&x * (n - 1) + quotient) / n;
+
+            if y >= x {
+                return x;
+            }
+
+            x = y;
+        }
+    }
+
+    pub fn is_perfect_square(&self) -> bool {
+        // Squares are { 0, 1, 4, 9 } modulo 16
+        if !matches!(self.digits.first().unwrap_or(&0) % 16, 0 | 1 | 4 | 9) {
+            return false;
+        }
+
+        self.sqrt_rem().1.is_zero()
+    }
+
+    /// Whether the number is m^k for some m and k ≥ 2 (0 and 1 included)
+    pub fn is_perfect_power(&self) -> bool {
+        if self.bits() <= 1 {
+            return true;
+        }
+
+        // It's enough to check prime exponents, m^(pq) = (m^q)^p,
+        // and a root bigger than 1 needs at least 2^k ≤ self.
+        (2..self.bits() as u32)
+            .filter(|k| (2..*k).take_while(|d| d * d <= *k).all(|d| k % d != 0))
+            .any(|k| self.nth_root(k).pow_uint(k as usize) == *self)
+    }
+}
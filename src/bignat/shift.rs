@@ -0,0 +1,83 @@
+use crate::{APNum, BigDigit, BigNat};
+
+impl std::ops::Shl<usize> for &BigNat {
+    type Output = BigNat;
+
+    fn shl(self, rhs: usize) -> Self::Output {
+        // Short-circuit
+        if self.is_zero() {
+            return BigNat::zero();
+        }
+
+        let digit_shift = rhs / BigDigit::BITS as usize;
+        let bit_shift = (rhs % BigDigit::BITS as usize) as u32;
+
+        let mut result = BigNat {
+            digits: vec![0; digit_shift],
+        };
+
+        if bit_shift == 0 {
+            result.digits.extend_from_slice(&self.digits);
+            return result;
+        }
+
+        let mut carry = 0;
+        for digit in &self.digits {
+            result.digits.push(digit << bit_shift | carry);
+            // The bits shifted out of the digit
+            carry = digit >> (BigDigit::BITS - bit_shift);
+        }
+
+        if carry > 0 {
+            result.digits.push(carry);
+        }
+
+        result
+    }
+}
+
+impl std::ops::Shl<usize> for BigNat {
+    type Output = BigNat;
+
+    fn shl(self, rhs: usize) -> Self::Output {
+        (&self).shl(rhs)
+    }
+}
+
+impl std::ops::Shr<usize> for &BigNat {
+    type Output = BigNat;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        let digit_shift = rhs / BigDigit::BITS as usize;
+        let bit_shift = (rhs % BigDigit::BITS as usize) as u32;
+
+        // Short-circuit
+        if digit_shift >= self.digit_count() {
+            return BigNat::zero();
+        }
+
+        let digits = &self.digits[digit_shift..];
+        if bit_shift == 0 {
+            return BigNat::from(digits);
+        }
+
+        let mut result = BigNat::zero();
+        for position in 0..digits.len() {
+            let high = digits.get(position + 1).unwrap_or(&0);
+            // Lower bits of the next digit become the higher bits of this one
+            result
+                .digits
+                .push(digits[position] >> bit_shift | high << (BigDigit::BITS - bit_shift));
+        }
+
+        result.zero_normalized()
+    }
+}
+
+impl std::ops::Shr<usize> for BigNat {
+    type Output = BigNat;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        (&self).shr(rhs)
+    }
+}
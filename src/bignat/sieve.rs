@@ -0,0 +1,92 @@
+use crate::{BigDigit, BigNat};
+
+// Bounds the memory of the sieve, both for the base primes and for a segment
+const SIEVE_LIMIT: BigDigit = 1 << 20;
+const SEGMENT_LENGTH: usize = 1 << 16;
+
+impl BigNat {
+    /// Primes in [low; high), by a segmented sieve of Eratosthenes.
+    /// Once the square root of high exceeds 2^20, the numbers surviving the sieve
+    /// are checked with is_probable_prime.
+    pub fn primes_between(low: &BigNat, high: &BigNat) -> Vec<BigNat> {
+        if low >= high {
+            return vec![];
+        }
+
+        // Every composite below high has a prime factor up to its square root
+        let root = high.sqrt();
+        let complete = root.cmp_u32(SIEVE_LIMIT).is_lt();
+        let base_primes = sieve(if complete {
+            (&root).try_into().unwrap()
+        } else {
+            SIEVE_LIMIT
+        });
+
+        let mut primes = vec![];
+        let mut start = low.clone();
+        while start < *high {
+            let remaining: Result<BigDigit, ()> = (&(high - &start).natural).try_into();
+            let length = match remaining {
+                Ok(remaining) if (remaining as usize) < SEGMENT_LENGTH => remaining as usize,
+                _ => SEGMENT_LENGTH,
+            };
+
+            // composite[i] for start + i
+            let mut composite = vec![false; length];
+            for prime in &base_primes {
+                let square = BigNat::from(*prime as u64 * *prime as u64);
+                let mut offset = if start < square {
+                    // Multiples below prime^2 have smaller prime factors, skip them
+                    let offset: Result<BigDigit, ()> = (&(&square - &start).natural).try_into();
+                    match offset {
+                        Ok(offset) => offset as usize,
+                        // Beyond the segment
+                        Err(()) => continue,
+                    }
+                } else {
+                    // First multiple from start
+                    let (_, remainder) = &start / *prime;
+                    ((prime - remainder) % prime) as usize
+                };
+
+                while offset < length {
+                    composite[offset] = true;
+                    offset += *prime as usize;
+                }
+            }
+
+            for (offset, composite) in composite.into_iter().enumerate() {
+                let number = &start + &BigNat::from(offset);
+                if composite || number.cmp_u8(2).is_lt() {
+                    continue;
+                }
+
+                if complete || number.is_probable_prime(0) {
+                    primes.push(number);
+                }
+            }
+
+            start = start + BigNat::from(length);
+        }
+
+        primes
+    }
+}
+
+/// Primes up to limit (inclusive), by the sieve of Eratosthenes
+pub(crate) fn sieve(limit: BigDigit) -> Vec<BigDigit> {
+    let limit = limit as usize;
+    let mut composite = vec![false; limit + 1];
+    let mut primes = vec![];
+    for number in 2..=limit {
+        if composite[number] {
+            continue;
+        }
+
+        primes.push(number as BigDigit);
+        for multiple in (number * number..=limit).step_by(number) {
+            composite[multiple] = true;
+        }
+    }
+    primes
+}
@@ -0,0 +1,249 @@
+use crate::symbol::{jacobi, legendre};
+use crate::{APNum, BigInt, BigNat};
+
+impl BigNat {
+    /// Square roots of self modulo an odd prime p (which isn't checked),
+    /// as (r, p - r) with r ≤ p - r, None if self isn't a quadratic residue
+    pub fn sqrt_mod(&self, p: &BigNat) -> Option<(BigNat, BigNat)> {
+        let (_, a) = self / p;
+        if a.is_zero() {
+            return Some((BigNat::zero(), BigNat::zero()));
+        }
+
+        if legendre(&BigInt::from(a.clone()), p) != 1 {
+            return None;
+        }
+
+        let root = if p.digits[0] % 4 == 3 {
+            // a^((p + 1) / 4) squares to a * a^((p - 1) / 2) = a
+            a.modpow(&((p + 1u8) >> 2), p)
+        } else {
+            // Tonelli–Shanks takes O(s^2) multiplications for p - 1 = 2^s * q,
+            // Cipolla is better once s is big.
+            let s = (p - 1u8).natural.trailing_zeros().unwrap();
+            if s * s > p.bits() {
+                cipolla(&a, p)
+            } else {
+                tonelli_shanks(&a, p)
+            }
+        };
+
+        let other = (p - &root).natural;
+        Some(if root <= other {
+            (root, other)
+        } else {
+            (other, root)
+        })
+    }
+
+    /// Square roots of self modulo p^k for a prime p (which isn't checked), in ascending order,
+    /// two of them for odd p, up to four for p = 2. None if there are no roots.
+    /// self has to be coprime to p.
+    pub fn sqrt_mod_prime_power(&self, p: &BigNat, k: u32) -> Option<Vec<BigNat>> {
+        if k == 0 {
+            panic!("Square root modulo p^0");
+        }
+
+        if (self / p).1.is_zero() {
+            panic!("Square root modulo p^k of a multiple of p");
+        }
+
+        let modulus = p.pow(k);
+        let (_, a) = self / &modulus;
+
+        let mut roots = if p.eq_u8(2) {
+            sqrt_mod_power_of_two(&a, k)?
+        } else {
+            let (root, _) = a.sqrt_mod(p)?;
+            let root = hensel_lift(&a, root, p, &modulus);
+            let other = (&modulus - &root).natural;
+            vec![root, other]
+        };
+
+        roots.sort();
+        Some(roots)
+    }
+
+    /// Square roots of self modulo n = p_1^k_1 * ... * p_m^k_m given as [(p_1, k_1), ...],
+    /// in ascending order, None if there are no roots. self has to be coprime to n.
+    pub fn sqrt_mod_composite(&self, factors: &[(BigNat, u32)]) -> Option<Vec<BigNat>> {
+        // Roots modulo the product of the prime powers so far
+        let mut roots = vec![BigNat::zero()];
+        let mut modulus = BigNat::from(1usize);
+
+        for (p, k) in factors {
+            let prime_power = p.pow(*k);
+            let prime_power_roots = self.sqrt_mod_prime_power(p, *k)?;
+
+            // Combine every pair of roots by the Chinese remainder theorem,
+            // x ≡ r (mod modulus), x ≡ s (mod prime_power)
+            //   => x = r + modulus * ((s - r) * modulus^-1 mod prime_power)
+            let inverse = modulus.mod_inverse(&prime_power).unwrap();
+            let mut combined = vec![];
+            for r in &roots {
+                let (_, r_residue) = r / &prime_power;
+                for s in &prime_power_roots {
+                    let difference = sub_mod(s, &r_residue, &prime_power);
+                    let (_, t) = &(&difference * &inverse) / &prime_power;
+                    combined.push(r + &(&modulus * &t));
+                }
+            }
+
+            roots = combined;
+            modulus = &modulus * &prime_power;
+        }
+
+        roots.sort();
+        Some(roots)
+    }
+}
+
+/// x - y (mod m) for x, y < m
+fn sub_mod(x: &BigNat, y: &BigNat, m: &BigNat) -> BigNat {
+    if x >= y {
+        (x - y).natural
+    } else {
+        (&(x + m) - y).natural
+    }
+}
+
+// see. Cohen, A Course in Computational Algebraic Number Theory, Algorithm 1.5.1
+// a is a nonzero quadratic residue modulo p
+fn tonelli_shanks(a: &BigNat, p: &BigNat) -> BigNat {
+    let reduce = |x: BigNat| (&x / p).1;
+
+    // p - 1 = 2^e * q, q odd
+    let p_minus_one = (p - 1u8).natural;
+    let e = p_minus_one.trailing_zeros().unwrap();
+    let q = &p_minus_one >> e;
+
+    // Generator z of the 2-Sylow subgroup, from a quadratic non-residue n
+    let n = (2u32..)
+        .find(|n| jacobi(&BigInt::from(*n), p) == -1)
+        .unwrap();
+    let z = BigNat::from(n).modpow(&q, p);
+
+    let mut y = z;
+    let mut r = e;
+    let mut x = a.modpow(&(&q >> 1), p);
+    let mut b = reduce(&(a * &x) * &x);
+    x = reduce(a * &x);
+
+    while !b.eq_u8(1) {
+        // Smallest m with b^(2^m) = 1
+        let mut m = 0;
+        let mut power = b.clone();
+        while !power.eq_u8(1) {
+            power = reduce(&power * &power);
+            m += 1;
+        }
+
+        let mut t = y;
+        for _ in 0..r - m - 1 {
+            t = reduce(&t * &t);
+        }
+
+        y = reduce(&t * &t);
+        r = m;
+        x = reduce(&x * &t);
+        b = reduce(&b * &y);
+    }
+
+    x
+}
+
+// see. Cohen, A Course in Computational Algebraic Number Theory, Section 1.5.2
+// a is a nonzero quadratic residue modulo p
+fn cipolla(a: &BigNat, p: &BigNat) -> BigNat {
+    let reduce = |x: BigNat| (&x / p).1;
+
+    // t with t^2 - a a non-residue, so that ω = sqrt(t^2 - a) is in F_p^2 but not in F_p
+    let (t, d) = (1u32..)
+        .map(|t| {
+            let t = BigNat::from(t);
+            let d = sub_mod(&reduce(&t * &t), a, p);
+            (t, d)
+        })
+        .find(|(_, d)| jacobi(&BigInt::from(d.clone()), p) == -1)
+        .unwrap();
+
+    // (x + yω)(u + vω) = (xu + yvd) + (xv + yu)ω
+    let mul = |(x, y): &(BigNat, BigNat), (u, v): &(BigNat, BigNat)| {
+        (
+            reduce(&(x * u) + &reduce(&(y * v) * &d)),
+            reduce(&(x * v) + &(y * u)),
+        )
+    };
+
+    // (t + ω)^((p + 1) / 2) = sqrt(a)
+    let exponent = (p + 1u8) >> 1;
+    let base = (t, BigNat::from(1usize));
+    let mut acc = (BigNat::from(1usize), BigNat::zero());
+    for bit in (0..exponent.bits()).rev() {
+        acc = mul(&acc, &acc);
+        if exponent.bit(bit) {
+            acc = mul(&acc, &base);
+        }
+    }
+
+    debug_assert!(acc.1.is_zero());
+    acc.0
+}
+
+/// Lifts a root of a modulo the odd prime p to a root modulo p^k (given as modulus)
+/// by Newton's iteration, r <- r - (r^2 - a) / 2r, doubling the exponent each step
+fn hensel_lift(a: &BigNat, mut root: BigNat, p: &BigNat, modulus: &BigNat) -> BigNat {
+    let mut current = p.clone();
+    while current < *modulus {
+        current = &current * &current;
+        if current > *modulus {
+            current = modulus.clone();
+        }
+
+        let reduce = |x: BigNat| (&x / &current).1;
+        let (_, a) = a / &current;
+        let f = sub_mod(&reduce(&root * &root), &a, &current);
+        let inverse = reduce(&root * 2u8).mod_inverse(&current).unwrap();
+        root = sub_mod(&root, &reduce(&f * &inverse), &current);
+    }
+    root
+}
+
+/// Square roots of an odd a modulo 2^k
+fn sqrt_mod_power_of_two(a: &BigNat, k: u32) -> Option<Vec<BigNat>> {
+    let residue = a.digits[0] % 8;
+    match k {
+        1 => return Some(vec![BigNat::from(1usize)]),
+        2 if residue % 4 == 1 => return Some(vec![BigNat::from(1usize), BigNat::from(3usize)]),
+        2 => return None,
+        _ if residue != 1 => return None,
+        _ => (),
+    }
+
+    // r^2 ≡ a (mod 2^i) holds from i = 3 with r = 1, if it fails for 2^(i + 1),
+    // r + 2^(i - 1) fixes it, (r + 2^(i - 1))^2 = r^2 + 2^i * r + 2^(2i - 2)
+    let mut root = BigNat::from(1usize);
+    for i in 3..k as usize {
+        let square = &root * &root;
+        let difference = if square >= *a {
+            (&square - a).natural
+        } else {
+            (a - &square).natural
+        };
+
+        if difference.bit(i) {
+            root = &root + &BigNat::power_of_two(i - 1);
+        }
+    }
+
+    // ±root and ±root + 2^(k - 1)
+    let modulus = BigNat::power_of_two(k as usize);
+    let half = BigNat::power_of_two(k as usize - 1);
+    let (_, shifted) = &(&root + &half) / &modulus;
+    Some(vec![
+        (&modulus - &root).natural,
+        (&modulus - &shifted).natural,
+        shifted,
+        root,
+    ])
+}
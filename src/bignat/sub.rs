@@ -1,4 +1,5 @@
 use crate::{BigInt, BigNat, Sign, APNum, BASE, BiggerDigit, BigDigit};
+use super::trim_digits;
 
 impl std::ops::Sub for &BigNat {
     type Output = BigInt;
@@ -73,6 +74,27 @@ impl std::ops::Sub for &BigNat {
     }
 }
 
+/// x -= y for x ≥ y, leading zeros of x are left as they are
+pub(crate) fn sub_assign_digits(x: &mut [BigDigit], y: &[BigDigit]) {
+    let y = trim_digits(y);
+
+    let mut borrowed = false;
+    for (position, left_digit) in x.iter_mut().enumerate() {
+        if position >= y.len() && !borrowed {
+            return;
+        }
+
+        let right_digit = *y.get(position).unwrap_or(&0);
+        let (digit, borrowed_by_digit) = left_digit.overflowing_sub(right_digit);
+        let (digit, borrowed_by_carry) = digit.overflowing_sub(borrowed as BigDigit);
+        *left_digit = digit;
+        borrowed = borrowed_by_digit || borrowed_by_carry;
+    }
+
+    // Cannot happen, x ≥ y
+    debug_assert!(!borrowed);
+}
+
 impl std::ops::Sub for BigNat {
     type Output = BigInt;
 
@@ -91,7 +113,7 @@ macro_rules! impl_digit_size_subtraction {
         
                 let (bigger, smaller) = match self.$cmp_func(rhs) {
                     Greater => (self, rhs),
-                    Less => return BigInt::from(-((rhs as BigDigit - self.digits[0]) as i64)),
+                    Less => return BigInt::from(-((rhs as BigDigit - *self.digits.first().unwrap_or(&0)) as i64)),
                     Equal => return BigInt::zero(),
                 };
         
@@ -0,0 +1,55 @@
+use crate::BigNat;
+
+impl BigNat {
+    /// Product of the factors by a balanced product tree, so the operands of the
+    /// multiplications have close sizes (and Karatsuba kicks in), 1 for no factors
+    pub fn product<I: IntoIterator<Item = BigNat>>(factors: I) -> BigNat {
+        let mut level: Vec<BigNat> = factors.into_iter().collect();
+        if level.is_empty() {
+            return BigNat::from(1usize);
+        }
+
+        while level.len() > 1 {
+            level = multiply_pairs(&level);
+        }
+        level.pop().unwrap()
+    }
+
+    /// self mod m for every m in moduli, by reducing self modulo the nodes of the
+    /// product tree of the moduli from the root down to the leaves
+    // see. Bernstein, Fast multiplication and its applications, Section 18
+    pub fn remainder_tree(&self, moduli: &[BigNat]) -> Vec<BigNat> {
+        if moduli.is_empty() {
+            return vec![];
+        }
+
+        // levels[0] are the moduli, the last level is their product
+        let mut levels = vec![moduli.to_vec()];
+        while levels.last().unwrap().len() > 1 {
+            let next = multiply_pairs(levels.last().unwrap());
+            levels.push(next);
+        }
+
+        let mut remainders = vec![(self / &levels.pop().unwrap()[0]).1];
+        for level in levels.iter().rev() {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(i, modulus)| (&remainders[i / 2] / modulus).1)
+                .collect();
+        }
+        remainders
+    }
+}
+
+/// Products of the adjacent pairs, an odd one out is carried over
+fn multiply_pairs(level: &[BigNat]) -> Vec<BigNat> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => left * right,
+            [single] => single.clone(),
+            _ => unreachable!(),
+        })
+        .collect()
+}
@@ -0,0 +1,37 @@
+use super::{exact_div, scale};
+use crate::BigRational;
+
+impl std::ops::Add for &BigRational {
+    type Output = BigRational;
+
+    // see. Knuth, The Art Of Computer Programming Vol. 2 Section 4.5.1
+    fn add(self, rhs: Self) -> Self::Output {
+        let g = self.denominator.gcd(&rhs.denominator);
+        if g.eq_u8(1) {
+            return BigRational {
+                numerator: &scale(&self.numerator, &rhs.denominator)
+                    + &scale(&rhs.numerator, &self.denominator),
+                denominator: &self.denominator * &rhs.denominator,
+            };
+        }
+
+        // t = a * (d / g) + c * (b / g) has no common factors with b / g and d / g
+        let (left, _) = &self.denominator / &g;
+        let (right, _) = &rhs.denominator / &g;
+        let t = &scale(&self.numerator, &right) + &scale(&rhs.numerator, &left);
+        let g = t.natural.gcd(&g);
+
+        BigRational {
+            numerator: exact_div(&t, &g),
+            denominator: &left * &(&rhs.denominator / &g).0,
+        }
+    }
+}
+
+impl std::ops::Add for BigRational {
+    type Output = BigRational;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        (&self).add(&rhs)
+    }
+}
@@ -0,0 +1,19 @@
+use super::scale;
+use crate::BigRational;
+
+impl std::cmp::Ord for BigRational {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self.denominator == other.denominator {
+            return self.numerator.cmp(&other.numerator);
+        }
+
+        // a/b ? c/d <=> ad ? cb, as the denominators are positive
+        scale(&self.numerator, &other.denominator).cmp(&scale(&other.numerator, &self.denominator))
+    }
+}
+
+impl std::cmp::PartialOrd for BigRational {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,19 @@
+use crate::BigRational;
+
+impl std::ops::Div for &BigRational {
+    type Output = BigRational;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        // a/b / c/d = a/b * d/c
+        self * &rhs.recip()
+    }
+}
+
+impl std::ops::Div for BigRational {
+    type Output = BigRational;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        (&self).div(&rhs)
+    }
+}
@@ -0,0 +1,299 @@
+pub mod add;
+pub mod cmp;
+pub mod div;
+pub mod mul;
+pub mod sub;
+
+use crate::{APNum, APNumParseError, BigInt, BigNat, BigRational, Sign};
+
+impl BigRational {
+    /// numerator / denominator, reduced to lowest terms
+    pub fn new(numerator: BigInt, denominator: BigNat) -> Self {
+        if denominator.is_zero() {
+            panic!("Division by Zero");
+        }
+
+        let gcd = numerator.natural.gcd(&denominator);
+        BigRational {
+            numerator: exact_div(&numerator, &gcd),
+            denominator: (&denominator / &gcd).0,
+        }
+    }
+
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &BigNat {
+        &self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator.eq_u8(1)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.numerator.is_negative()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.numerator.is_positive()
+    }
+
+    pub fn abs(&self) -> Self {
+        BigRational {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// 1 / self
+    pub fn recip(&self) -> Self {
+        if self.is_zero() {
+            panic!("Division by Zero");
+        }
+
+        BigRational {
+            numerator: BigInt {
+                sign: self.numerator.sign.clone(),
+                natural: self.denominator.clone(),
+            },
+            denominator: self.numerator.natural.clone(),
+        }
+    }
+
+    /// self^power, negative powers of zero panic
+    pub fn pow(&self, power: i32) -> Self {
+        let base = if power < 0 {
+            self.recip()
+        } else {
+            self.clone()
+        };
+
+        // Powers of coprime numbers are coprime
+        BigRational {
+            numerator: base.numerator.pow(power.unsigned_abs()),
+            denominator: base.denominator.pow(power.unsigned_abs()),
+        }
+    }
+
+    /// Greatest integer less than or equal to self
+    pub fn floor(&self) -> BigInt {
+        // BigInt division rounds toward negative infinity
+        (&self.numerator / &BigInt::from(self.denominator.clone())).0
+    }
+
+    /// Least integer greater than or equal to self
+    pub fn ceil(&self) -> BigInt {
+        -(-self).floor()
+    }
+
+    /// Integer part of self, rounding toward zero
+    pub fn trunc(&self) -> BigInt {
+        exact_div(&self.numerator, &self.denominator)
+    }
+
+    /// Nearest integer to self, rounding half away from zero
+    pub fn round(&self) -> BigInt {
+        // floor((2|n| + d) / 2d)
+        let twice = &self.numerator.natural << 1;
+        let (natural, _) = &(&twice + &self.denominator) / &(&self.denominator << 1);
+        BigInt {
+            sign: self.numerator.sign.clone(),
+            natural,
+        }
+        .zero_normalized()
+    }
+}
+
+/// x * y
+fn scale(x: &BigInt, y: &BigNat) -> BigInt {
+    BigInt {
+        sign: x.sign.clone(),
+        natural: &x.natural * y,
+    }
+    .zero_normalized()
+}
+
+/// x / y rounded toward zero, exact when y divides x
+fn exact_div(x: &BigInt, y: &BigNat) -> BigInt {
+    BigInt {
+        sign: x.sign.clone(),
+        natural: (&x.natural / y).0,
+    }
+    .zero_normalized()
+}
+
+impl APNum for BigRational {
+    fn zero() -> Self {
+        BigRational {
+            numerator: BigInt::zero(),
+            denominator: BigNat::from(1usize),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    fn zero_normalized(self) -> Self {
+        self
+    }
+
+    fn digit_count(&self) -> usize {
+        self.numerator.digit_count() + self.denominator.digit_count()
+    }
+}
+
+impl std::ops::Neg for &BigRational {
+    type Output = BigRational;
+
+    fn neg(self) -> Self::Output {
+        BigRational {
+            numerator: -&self.numerator,
+            denominator: self.denominator.clone(),
+        }
+    }
+}
+
+impl std::ops::Neg for BigRational {
+    type Output = BigRational;
+
+    fn neg(self) -> Self::Output {
+        -(&self)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty)*) => ($(
+        impl From<$t> for BigRational {
+            fn from(value: $t) -> Self {
+                BigRational::from(BigInt::from(value))
+            }
+        }
+    )*)
+}
+
+impl_from_integer!(usize u8 u16 u32 u64 isize i8 i16 i32 i64);
+
+impl From<BigInt> for BigRational {
+    fn from(value: BigInt) -> Self {
+        BigRational {
+            numerator: value,
+            denominator: BigNat::from(1usize),
+        }
+    }
+}
+
+impl From<BigNat> for BigRational {
+    fn from(value: BigNat) -> Self {
+        BigRational::from(BigInt::from(value))
+    }
+}
+
+impl std::str::FromStr for BigRational {
+    type Err = APNumParseError;
+
+    /// Either a fraction, "-3/4", or a decimal, "-0.75"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(APNumParseError::Empty);
+        }
+
+        if let Some((numerator, denominator)) = s.split_once('/') {
+            let numerator = numerator.parse::<BigInt>()?;
+            let denominator = denominator.parse::<BigNat>()?;
+            if denominator.is_zero() {
+                return Err(APNumParseError::Invalid);
+            }
+
+            return Ok(BigRational::new(numerator, denominator));
+        }
+
+        let (sign, s) = match s.strip_prefix('-') {
+            Some(s) => (Sign::Negative, s),
+            None => (Sign::Positive, s),
+        };
+
+        let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+        if s.contains('.') && (integer.is_empty() || fraction.is_empty()) {
+            return Err(APNumParseError::Invalid);
+        }
+
+        let natural = format!("{integer}{fraction}").parse::<BigNat>()?;
+        let numerator = BigInt { sign, natural }.zero_normalized();
+        let denominator = BigNat::from(10usize).pow(fraction.len() as u32);
+        Ok(BigRational::new(numerator, denominator))
+    }
+}
+
+impl TryFrom<&str> for BigRational {
+    type Error = APNumParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::fmt::Display for BigRational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            return std::fmt::Display::fmt(&self.numerator, f);
+        }
+
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+impl std::fmt::Debug for BigRational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{APNum, APNumParseError, BigRational, Sign};
+
+    #[test]
+    fn fraction() {
+        let result = "-6/8".parse::<BigRational>();
+        assert!(result.is_ok_and(
+            |rational| matches!(rational.numerator.natural.digits[..], [3])
+                && rational.numerator.sign == Sign::Negative
+                && matches!(rational.denominator.digits[..], [4])
+        ))
+    }
+
+    #[test]
+    fn decimal() {
+        let result = "-0.125".parse::<BigRational>();
+        assert!(result.is_ok_and(
+            |rational| matches!(rational.numerator.natural.digits[..], [1])
+                && rational.numerator.sign == Sign::Negative
+                && matches!(rational.denominator.digits[..], [8])
+        ))
+    }
+
+    #[test]
+    fn zero() {
+        let result = "-0.000".parse::<BigRational>();
+        assert!(result.is_ok_and(|rational| rational.is_zero() && rational.is_integer()))
+    }
+
+    #[test]
+    fn zero_denominator() {
+        let result = "1/0".parse::<BigRational>();
+        assert!(result.is_err_and(|err| matches!(err, APNumParseError::Invalid)))
+    }
+
+    #[test]
+    fn missing_digits() {
+        let result = "1.".parse::<BigRational>();
+        assert!(result.is_err_and(|err| matches!(err, APNumParseError::Invalid)));
+        let result = ".5".parse::<BigRational>();
+        assert!(result.is_err_and(|err| matches!(err, APNumParseError::Invalid)));
+        let result = "3/".parse::<BigRational>();
+        assert!(result.is_err_and(|err| matches!(err, APNumParseError::Empty)))
+    }
+}
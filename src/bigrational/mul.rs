@@ -0,0 +1,30 @@
+use super::exact_div;
+use crate::{APNum, BigRational};
+
+impl std::ops::Mul for &BigRational {
+    type Output = BigRational;
+
+    // see. Knuth, The Art Of Computer Programming Vol. 2 Section 4.5.1
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return BigRational::zero();
+        }
+
+        // Cancel the common factors across before multiplying
+        let g = self.numerator.natural.gcd(&rhs.denominator);
+        let h = rhs.numerator.natural.gcd(&self.denominator);
+
+        BigRational {
+            numerator: &exact_div(&self.numerator, &g) * &exact_div(&rhs.numerator, &h),
+            denominator: &(&self.denominator / &h).0 * &(&rhs.denominator / &g).0,
+        }
+    }
+}
+
+impl std::ops::Mul for BigRational {
+    type Output = BigRational;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        (&self).mul(&rhs)
+    }
+}
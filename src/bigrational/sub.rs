@@ -0,0 +1,17 @@
+use crate::BigRational;
+
+impl std::ops::Sub for &BigRational {
+    type Output = BigRational;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &(-rhs)
+    }
+}
+
+impl std::ops::Sub for BigRational {
+    type Output = BigRational;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        (&self).sub(&rhs)
+    }
+}
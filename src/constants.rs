@@ -0,0 +1,166 @@
+// Mathematical constants to any number of decimal digits
+
+use std::f64::consts::{LN_2, LOG2_10};
+
+use crate::bigfloat::ball::{div_int, shl_int, Ball};
+use crate::bigfloat::log::ln_ball;
+use crate::bigfloat::series::{self, log_factorial, sum, term_count, Term};
+use crate::{BigFloat, BigInt, BigNat, RoundingMode};
+
+/// Functions giving the constant times 10^digits truncated to an integer, and its decimal
+/// representation with that many digits after the point
+macro_rules! constants {
+    ($($doc:literal $name:ident $string:ident => $ball:path;)*) => ($(
+        #[doc = concat!($doc, " * 10^digits truncated to an integer")]
+        pub fn $name(digits: usize) -> BigInt {
+            truncated(digits, $ball)
+        }
+
+        #[doc = concat!($doc, " with the given number of decimal digits after the point, truncated")]
+        pub fn $string(digits: usize) -> String {
+            decimal_string(&$name(digits), digits)
+        }
+    )*)
+}
+
+constants! {
+    "π" pi pi_string => series::pi;
+    "e" e e_string => e_ball;
+    "ln 2" ln2 ln2_string => series::ln2;
+    "√2" sqrt2 sqrt2_string => sqrt2_ball;
+    "The Euler–Mascheroni constant γ" euler_gamma euler_gamma_string => euler_gamma_ball;
+    "Catalan's constant G" catalan catalan_string => catalan_ball;
+}
+
+/// A positive constant, given as a ball at a binary scale, times 10^digits truncated. The scale
+/// grows until the whole ball truncates to the same integer.
+fn truncated(digits: usize, ball: impl Fn(usize) -> Ball) -> BigInt {
+    let exponent = u32::try_from(digits).expect("Too many digits");
+    let power = BigInt::from(BigNat::from(10usize).pow(exponent));
+    let mut guard = 32;
+    loop {
+        let scale = (digits as f64 * LOG2_10).ceil() as usize + guard;
+        let ball = ball(scale);
+        let radius = shl_int(&BigInt::from(1), ball.radius.max(0.0).ceil() as usize);
+        let [low, high] = [&ball.center - &radius, &ball.center + &radius]
+            .map(|bound| (&(&bound * &power).natural >> scale, bound.is_negative()));
+        if !low.1 && low == high {
+            return BigInt::from(low.0);
+        }
+        guard *= 2;
+    }
+}
+
+/// "d.ddd" from the value times 10^digits
+fn decimal_string(scaled: &BigInt, digits: usize) -> String {
+    let digits_string = format!("{:0>width$}", scaled.to_string(), width = digits + 1);
+    let (integer, fraction) = digits_string.split_at(digits_string.len() - digits);
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+/// e = Σ 1 / k!
+fn e_ball(scale: usize) -> Ball {
+    let count = term_count(scale + 2, |k| -log_factorial(k));
+    sum(
+        count,
+        |k| match k {
+            0 => Term::ratio(BigInt::from(1), BigInt::from(1)),
+            _ => Term::ratio(BigInt::from(1), BigInt::from(k)),
+        },
+        scale,
+    )
+}
+
+fn sqrt2_ball(scale: usize) -> Ball {
+    Ball::exact(shl_int(&BigInt::from(2), scale), scale).sqrt()
+}
+
+/// γ = U / V - ln n, with U = Σ (n^k / k!)^2 H(k) and V = Σ (n^k / k!)^2, within π e^(-4n)
+// see. Brent and McMillan, Some new algorithms for high-precision computation of Euler's
+// constant (1980)
+fn euler_gamma_ball(scale: usize) -> Ball {
+    let working = scale + 8;
+    let n = ((working + 4) as f64 * LN_2 / 4.0).ceil() as u64 + 1;
+    let log_n = (n as f64).log2();
+
+    // The terms are below 2^-working relative to ln n
+    let count = term_count(working + 4 + log_n.log2().max(0.0) as usize, |k| {
+        2.0 * (k as f64 * log_n - log_factorial(k)) + (k as f64).log2() + 1.0
+    });
+
+    // U / V = (V' / (Q D)) / (1 + T / Q) = V' / (D (Q + T))
+    let [_, q, t, _, d, v] = harmonic_split(1, count.max(2), &BigInt::from(n));
+    let ratio = Ball {
+        center: div_int(&shl_int(&v, working), &(&d * &(&q + &t))),
+        // A unit for the rest of the series, one for the truncation and one for π e^(-4n)
+        radius: 3f64.log2(),
+        scale: working,
+    };
+
+    let n = BigFloat::new(BigInt::from(n), 0, 64, RoundingMode::Down);
+    ratio.sub(&ln_ball(&n, working)).rescaled(8)
+}
+
+/// Binary splitting of the terms of U and V in [start; end): P, Q and T as for a plain series with
+/// the term ratios n^2 / k^2, C / D = Σ 1 / k, and V / (Q D) = Σ (Π p / q) H(k) within the range
+fn harmonic_split(start: u64, end: u64, n: &BigInt) -> [BigInt; 6] {
+    if end - start == 1 {
+        let k = BigInt::from(start);
+        let p = n * n;
+        return [p.clone(), &k * &k, p.clone(), BigInt::from(1), k, p];
+    }
+
+    let middle = start + (end - start) / 2;
+    let [lp, lq, lt, lc, ld, lv] = harmonic_split(start, middle, n);
+    let [rp, rq, rt, rc, rd, rv] = harmonic_split(middle, end, n);
+    let v = &(&rd * &(&(&rq * &lv) + &(&(&lp * &lc) * &rt))) + &(&(&ld * &lp) * &rv);
+    [
+        &lp * &rp,
+        &lq * &rq,
+        &(&lt * &rq) + &(&lp * &rt),
+        &(&lc * &rd) + &(&rc * &ld),
+        &ld * &rd,
+        v,
+    ]
+}
+
+/// G = π/8 ln(2 + √3) + 3/8 Σ 1 / ((2k + 1)^2 (2k choose k))
+// see. Bradley, Representations of Catalan's constant (2001)
+fn catalan_ball(scale: usize) -> Ball {
+    let working = scale + 8;
+
+    // The terms fall by 1/4 at least
+    let count = (working as u64 + 4) / 2 + 1;
+    let sum = sum(
+        count,
+        |k| match k {
+            0 => Term::ratio(BigInt::from(1), BigInt::from(1)),
+            _ => Term {
+                a: BigInt::from(1),
+                b: BigInt::from((2 * k + 1) * (2 * k + 1)),
+                p: BigInt::from(k),
+                q: BigInt::from(2 * (2 * k - 1)),
+            },
+        },
+        working,
+    );
+
+    // 2 + √3 rounded down within 2^-(working + 14), then ln is off by less than a unit
+    let precision = working + 16;
+    let three = BigFloat::new(BigInt::from(3), 0, 2, RoundingMode::Down);
+    let two = BigFloat::new(BigInt::from(2), 0, 2, RoundingMode::Down);
+    let root = three
+        .sqrt_rounded(precision, RoundingMode::Down)
+        .add_rounded(&two, precision, RoundingMode::Down);
+    let log = ln_ball(&root, working).widened(0.0);
+
+    let pi = series::pi(working);
+    pi.mul(&log)
+        .add(&sum.mul_int(&BigInt::from(3)))
+        .shr(3)
+        .rescaled(8)
+}
@@ -0,0 +1,132 @@
+// Continued fractions
+
+use crate::{APNum, BigInt, BigNat, BigRational};
+
+/// Partial quotients [a0; a1, a2, ...] of numerator / denominator, the last one is more than 1
+/// unless it is a0
+pub fn expand(numerator: &BigInt, denominator: &BigNat) -> Vec<BigInt> {
+    if denominator.is_zero() {
+        panic!("Division by Zero");
+    }
+
+    // Euclid's algorithm with floor division, then a0 may be negative and the others are positive
+    let mut terms = vec![];
+    let (mut n, mut d) = (numerator.clone(), BigInt::from(denominator.clone()));
+    while !d.is_zero() {
+        let (quotient, remainder) = &n / &d;
+        terms.push(quotient);
+        (n, d) = (d, remainder);
+    }
+    terms
+}
+
+/// (a0, [a1, ..., ak]) with sqrt(n) = [a0; a1, ..., ak, a1, ..., ak, ...], the period is empty for
+/// squares
+pub fn expand_sqrt(n: &BigNat) -> (BigNat, Vec<BigNat>) {
+    let a0 = n.sqrt();
+    let mut period = vec![];
+    if &a0 * &a0 == *n {
+        return (a0, period);
+    }
+
+    // The complete quotients are (sqrt(n) + m) / d, the period ends at the first a = 2 a0
+    let (mut m, mut d, mut a) = (BigNat::zero(), BigNat::from(1usize), a0.clone());
+    let end = &a0 << 1;
+    while a != end {
+        m = (&(&a * &d) - &m).natural;
+        d = (&(n - &(&m * &m)).natural / &d).0;
+        a = (&(&a0 + &m) / &d).0;
+        period.push(a.clone());
+    }
+    (a0, period)
+}
+
+/// Convergents p / q of partial quotients [a0; a1, a2, ...], with positive q
+pub fn convergents<I>(terms: I) -> Convergents<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: Into<BigInt>,
+{
+    Convergents {
+        terms: terms.into_iter(),
+        previous: (BigInt::zero(), BigInt::from(1)),
+        current: (BigInt::from(1), BigInt::zero()),
+    }
+}
+
+pub struct Convergents<I> {
+    terms: I,
+    /// p(k - 2) / q(k - 2) and p(k - 1) / q(k - 1), starting from 0 / 1 and 1 / 0
+    previous: (BigInt, BigInt),
+    current: (BigInt, BigInt),
+}
+
+impl<I> Iterator for Convergents<I>
+where
+    I: Iterator,
+    I::Item: Into<BigInt>,
+{
+    type Item = (BigInt, BigInt);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a: BigInt = self.terms.next()?.into();
+
+        // p(k) = a(k) p(k - 1) + p(k - 2), and the same for q
+        let p = &(&a * &self.current.0) + &self.previous.0;
+        let q = &(&a * &self.current.1) + &self.previous.1;
+        self.previous = std::mem::replace(&mut self.current, (p, q));
+        Some(self.current.clone())
+    }
+}
+
+/// The closest rational to value with a denominator at most max_denominator, the one with the
+/// smaller denominator on ties
+pub fn best_approximation(value: &BigRational, max_denominator: &BigNat) -> BigRational {
+    if max_denominator.is_zero() {
+        panic!("Zero denominator bound");
+    }
+    if value.denominator() <= max_denominator {
+        return value.clone();
+    }
+
+    // The best approximation is either the last convergent within the bound, or the
+    // semiconvergent between it and the previous one with the largest denominator in the bound
+    let bound = BigInt::from(max_denominator.clone());
+    let (mut p0, mut q0, mut p1, mut q1) = (
+        BigInt::zero(),
+        BigInt::from(1),
+        BigInt::from(1),
+        BigInt::zero(),
+    );
+    let (mut n, mut d) = (
+        value.numerator().clone(),
+        BigInt::from(value.denominator().clone()),
+    );
+    loop {
+        let (a, remainder) = &n / &d;
+        let q2 = &q0 + &(&a * &q1);
+        if q2 > bound {
+            break;
+        }
+        let p2 = &p0 + &(&a * &p1);
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+        (n, d) = (d, remainder);
+    }
+
+    let k = (&(&bound - &q0) / &q1).0;
+    let semiconvergent = BigRational::new(&p0 + &(&k * &p1), (&q0 + &(&k * &q1)).natural);
+    let convergent = BigRational::new(p1, q1.natural);
+    let semiconvergent_error = (&semiconvergent - value).abs();
+    let convergent_error = (&convergent - value).abs();
+    match semiconvergent_error.cmp(&convergent_error) {
+        std::cmp::Ordering::Less => semiconvergent,
+        std::cmp::Ordering::Greater => convergent,
+        std::cmp::Ordering::Equal => {
+            if semiconvergent.denominator() < convergent.denominator() {
+                semiconvergent
+            } else {
+                convergent
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+// Chinese remainder theorem
+
+use crate::{APNum, BigDigit, BigInt, BigNat, BiggerDigit};
+
+/// x with x ≡ a_i (mod m_i) for every (a_i, m_i) as (x, m), where m = lcm(m_1, ..., m_k)
+/// and 0 ≤ x < m. The moduli don't have to be coprime, None if the congruences are inconsistent.
+pub fn crt(residues: &[(BigInt, BigNat)]) -> Option<(BigInt, BigNat)> {
+    let mut x = BigNat::zero();
+    let mut modulus = BigNat::from(1usize);
+
+    for (a, m) in residues {
+        if m.is_zero() {
+            panic!("Zero modulus");
+        }
+
+        // Floor division, so the remainder is in [0; m)
+        let (_, a) = a / &BigInt::from(m.clone());
+        let a = BigNat::try_from(a).unwrap();
+        let (_, x_residue) = &x / m;
+
+        // x + modulus * t ≡ a (mod m) <=> modulus * t ≡ a - x (mod m),
+        // which is solvable only if g = gcd(modulus, m) divides a - x
+        let difference = if a >= x_residue {
+            (&a - &x_residue).natural
+        } else {
+            (&(&a + m) - &x_residue).natural
+        };
+
+        let g = modulus.gcd(m);
+        let (difference, remainder) = &difference / &g;
+        if !remainder.is_zero() {
+            return None;
+        }
+
+        // t ≡ (a - x) / g * (modulus / g)^-1 (mod m / g)
+        let (m, _) = m / &g;
+        let (_, reduced_modulus) = &(&modulus / &g).0 / &m;
+        let inverse = reduced_modulus.mod_inverse(&m).unwrap();
+        let (_, t) = &(&difference * &inverse) / &m;
+
+        // x < modulus * (m / g) = lcm(modulus, m)
+        x = &x + &(&modulus * &t);
+        modulus = &modulus * &m;
+    }
+
+    Some((BigInt::from(x), modulus))
+}
+
+/// x with x ≡ a_i (mod m_i) for every (a_i, m_i) and 0 ≤ x < m_1 * ... * m_k,
+/// by Garner's algorithm, which does most of the work on single digits.
+/// The moduli have to be pairwise coprime.
+// see. Knuth, The Art Of Computer Programming Vol. 2 Section 4.3.2
+pub fn garner(residues: &[(BigDigit, BigDigit)]) -> BigNat {
+    // Mixed radix digits, x = v_1 + m_1 * (v_2 + m_2 * (v_3 + ...))
+    let mut digits: Vec<BigDigit> = Vec::with_capacity(residues.len());
+
+    for (j, (a, m_j)) in residues.iter().enumerate() {
+        if *m_j == 0 {
+            panic!("Zero modulus");
+        }
+
+        // v_j = (...((a_j - v_1) * c_1j - v_2) * c_2j - ... - v_(j-1)) * c_(j-1)j mod m_j,
+        // where c_ij = m_i^-1 mod m_j
+        let m_j = *m_j as BiggerDigit;
+        let mut v = *a as BiggerDigit % m_j;
+        for (i, (_, m_i)) in residues[..j].iter().enumerate() {
+            let inverse = inverse_digit(*m_i as BiggerDigit % m_j, m_j)
+                .expect("moduli have to be pairwise coprime");
+            // Values are in [0; m_j), so the products fit in BiggerDigit
+            v = (v + m_j - digits[i] as BiggerDigit % m_j) % m_j * inverse % m_j;
+        }
+
+        digits.push(v as BigDigit);
+    }
+
+    let mut x = BigNat::zero();
+    for ((_, m), v) in residues.iter().zip(digits).rev() {
+        x = &(&x * *m) + v;
+    }
+    x
+}
+
+/// a^-1 mod m for a < m
+fn inverse_digit(a: BiggerDigit, m: BiggerDigit) -> Option<BiggerDigit> {
+    let (mut u, mut v) = (m as i64, a as i64);
+    let (mut s, mut t) = (0i64, 1i64);
+    while v != 0 {
+        let q = u / v;
+        (u, v) = (v, u - q * v);
+        (s, t) = (t, s - q * t);
+    }
+
+    if u != 1 {
+        return None;
+    }
+
+    Some(s.rem_euclid(m as i64) as BiggerDigit)
+}
@@ -0,0 +1,172 @@
+// Integer factorization
+//
+// Trial division by the small primes, then the cofactor is split by Pollard's p - 1
+// and Pollard's rho (Brent's variant) until every part passes the primality test.
+// There is no elliptic curve method yet, so cofactors with two large (~30+ digits)
+// prime factors can take a long time.
+
+use crate::bignat::sieve::sieve;
+use crate::{APNum, BigDigit, BigNat};
+
+const TRIAL_DIVISION_LIMIT: BigDigit = 1 << 14;
+const P_MINUS_ONE_BOUND: BigDigit = 1 << 14;
+// Number of steps between the gcd computations of Brent's variant
+const RHO_BATCH: usize = 128;
+
+/// Prime factorization in ascending order of the primes, 1 has no factors
+pub fn factor(n: &BigNat) -> Vec<(BigNat, u32)> {
+    if n.is_zero() {
+        panic!("Factorization of zero");
+    }
+
+    let mut factors = vec![];
+    let mut n = n.clone();
+
+    let primes = sieve(TRIAL_DIVISION_LIMIT);
+    for prime in &primes {
+        let mut exponent = 0;
+        loop {
+            let (quotient, remainder) = &n / *prime;
+            if remainder != 0 {
+                break;
+            }
+
+            n = quotient;
+            exponent += 1;
+        }
+
+        if exponent > 0 {
+            factors.push((BigNat::from(*prime), exponent));
+        }
+    }
+
+    // Split the cofactor until only primes are left
+    let mut composites = vec![];
+    if !n.eq_u8(1) {
+        composites.push(n);
+    }
+
+    while let Some(n) = composites.pop() {
+        if n.is_probable_prime(0) {
+            factors.push((n, 1));
+            continue;
+        }
+
+        let divisor = find_divisor(&n, &primes);
+        let (cofactor, _) = &n / &divisor;
+        composites.push(divisor);
+        composites.push(cofactor);
+    }
+
+    // Merge the repeated primes
+    factors.sort();
+    let mut merged: Vec<(BigNat, u32)> = vec![];
+    for (prime, exponent) in factors {
+        match merged.last_mut() {
+            Some((last, count)) if *last == prime => *count += exponent,
+            _ => merged.push((prime, exponent)),
+        }
+    }
+    merged
+}
+
+/// Nontrivial divisor of a composite with no small factors
+fn find_divisor(n: &BigNat, primes: &[BigDigit]) -> BigNat {
+    // Neither of the methods can split the powers of a prime reliably
+    if n.is_perfect_square() {
+        return n.sqrt();
+    }
+
+    for exponent in primes.iter().skip(1).take_while(|p| **p as usize <= n.bits()) {
+        let root = n.nth_root(*exponent);
+        if root.pow(*exponent) == *n {
+            return root;
+        }
+    }
+
+    if let Some(divisor) = pollard_p_minus_one(n, primes) {
+        return divisor;
+    }
+
+    (1u32..)
+        .find_map(|c| pollard_brent(n, c))
+        .unwrap()
+}
+
+// see. Pollard, Theorems on factorization and primality testing (1974)
+// Stage 1 only, finds p when p - 1 is P_MINUS_ONE_BOUND-smooth
+fn pollard_p_minus_one(n: &BigNat, primes: &[BigDigit]) -> Option<BigNat> {
+    let mut a = BigNat::from(2usize);
+    for prime in primes.iter().take_while(|p| **p <= P_MINUS_ONE_BOUND) {
+        // Highest power of prime up to the bound
+        let mut power = *prime;
+        while power <= P_MINUS_ONE_BOUND / prime {
+            power *= prime;
+        }
+
+        a = a.modpow(&BigNat::from(power), n);
+    }
+
+    let g = BigNat::try_from(&a - 1u8).ok()?.gcd(n);
+    if g.eq_u8(1) || g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+// see. Brent, An improved Monte Carlo factorization algorithm (1980)
+// x -> x^2 + c mod n, None on failure
+fn pollard_brent(n: &BigNat, c: u32) -> Option<BigNat> {
+    let f = |x: &BigNat| (&(&(x * x) + c) / n).1;
+    let distance = |x: &BigNat, y: &BigNat| (x - y).natural;
+
+    let mut y = BigNat::from(2usize);
+    let mut x;
+    let mut ys = y.clone();
+    let mut q = BigNat::from(1usize);
+    let mut g = BigNat::from(1usize);
+    let mut r = 1;
+
+    loop {
+        x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+
+        let mut k = 0;
+        while k < r && g.eq_u8(1) {
+            ys = y.clone();
+            // Accumulate the differences, and take one gcd for the batch
+            for _ in 0..RHO_BATCH.min(r - k) {
+                y = f(&y);
+                q = (&(&q * &distance(&x, &y)) / n).1;
+            }
+
+            g = q.gcd(n);
+            k += RHO_BATCH;
+        }
+
+        r *= 2;
+        if !g.eq_u8(1) {
+            break;
+        }
+    }
+
+    // The batch overshot, step back to its beginning and go one by one
+    if g == *n {
+        loop {
+            ys = f(&ys);
+            g = distance(&x, &ys).gcd(n);
+            if !g.eq_u8(1) {
+                break;
+            }
+        }
+    }
+
+    if g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}
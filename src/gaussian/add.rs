@@ -0,0 +1,17 @@
+use crate::GaussianInt;
+
+impl std::ops::Add for &GaussianInt {
+    type Output = GaussianInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        GaussianInt::new(&self.real + &rhs.real, &self.imaginary + &rhs.imaginary)
+    }
+}
+
+impl std::ops::Add for GaussianInt {
+    type Output = GaussianInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        (&self).add(&rhs)
+    }
+}
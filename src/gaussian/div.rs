@@ -0,0 +1,38 @@
+use crate::{APNum, BigInt, BigNat, GaussianInt};
+
+impl std::ops::Div for &GaussianInt {
+    type Output = (GaussianInt, GaussianInt);
+
+    /// (q, r) with self = q * rhs + r, q being self / rhs with both parts rounded to nearest, so
+    /// that norm(r) ≤ norm(rhs) / 2
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.is_zero() {
+            panic!("Division by Zero");
+        }
+
+        // self / rhs = self * conj(rhs) / norm(rhs)
+        let numerator = self * &rhs.conjugate();
+        let norm = rhs.norm();
+        let q = GaussianInt::new(
+            nearest(&numerator.real, &norm),
+            nearest(&numerator.imaginary, &norm),
+        );
+        let r = self - &(&q * rhs);
+        (q, r)
+    }
+}
+
+impl std::ops::Div for GaussianInt {
+    type Output = (GaussianInt, GaussianInt);
+
+    fn div(self, rhs: Self) -> Self::Output {
+        (&self).div(&rhs)
+    }
+}
+
+/// x / n rounded to nearest, ties toward positive infinity, floor((2x + n) / 2n)
+fn nearest(x: &BigInt, n: &BigNat) -> BigInt {
+    let n = BigInt::from(n.clone());
+    let twice = &n + &n;
+    (&(&(x + x) + &n) / &twice).0
+}
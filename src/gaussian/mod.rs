@@ -0,0 +1,150 @@
+pub mod add;
+pub mod div;
+pub mod mul;
+pub mod sub;
+
+use crate::{APNum, BigInt, BigNat, GaussianInt};
+
+impl GaussianInt {
+    pub fn new(real: BigInt, imaginary: BigInt) -> Self {
+        GaussianInt { real, imaginary }
+    }
+
+    /// The imaginary unit i
+    pub fn i() -> Self {
+        GaussianInt::new(BigInt::zero(), BigInt::from(1))
+    }
+
+    pub fn real(&self) -> &BigInt {
+        &self.real
+    }
+
+    pub fn imaginary(&self) -> &BigInt {
+        &self.imaginary
+    }
+
+    /// real^2 + imaginary^2
+    pub fn norm(&self) -> BigNat {
+        &(&self.real.natural * &self.real.natural)
+            + &(&self.imaginary.natural * &self.imaginary.natural)
+    }
+
+    /// real - imaginary * i
+    pub fn conjugate(&self) -> Self {
+        GaussianInt::new(self.real.clone(), -&self.imaginary)
+    }
+
+    /// self * i
+    fn rotated(&self) -> Self {
+        GaussianInt::new(-&self.imaginary, self.real.clone())
+    }
+
+    /// One of 1, i, -1 and -i
+    pub fn is_unit(&self) -> bool {
+        self.norm().eq_u8(1)
+    }
+
+    /// Associate of self with positive real part and nonnegative imaginary part, zero stays zero
+    pub fn normalized(&self) -> Self {
+        let mut result = self.clone();
+        while !result.is_zero() && (!result.real.is_positive() || result.imaginary.is_negative()) {
+            result = result.rotated();
+        }
+        result
+    }
+
+    /// Greatest common divisor, normalized
+    pub fn gcd(&self, other: &GaussianInt) -> GaussianInt {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let (_, r) = &a / &b;
+            (a, b) = (b, r);
+        }
+        a.normalized()
+    }
+}
+
+impl APNum for GaussianInt {
+    fn zero() -> Self {
+        GaussianInt::new(BigInt::zero(), BigInt::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.imaginary.is_zero()
+    }
+
+    fn zero_normalized(self) -> Self {
+        GaussianInt::new(
+            self.real.zero_normalized(),
+            self.imaginary.zero_normalized(),
+        )
+    }
+
+    fn digit_count(&self) -> usize {
+        self.real.digit_count() + self.imaginary.digit_count()
+    }
+}
+
+impl std::ops::Neg for &GaussianInt {
+    type Output = GaussianInt;
+
+    fn neg(self) -> Self::Output {
+        GaussianInt::new(-&self.real, -&self.imaginary)
+    }
+}
+
+impl std::ops::Neg for GaussianInt {
+    type Output = GaussianInt;
+
+    fn neg(self) -> Self::Output {
+        -(&self)
+    }
+}
+
+impl From<BigInt> for GaussianInt {
+    fn from(value: BigInt) -> Self {
+        GaussianInt::new(value, BigInt::zero())
+    }
+}
+
+impl From<(BigInt, BigInt)> for GaussianInt {
+    fn from((real, imaginary): (BigInt, BigInt)) -> Self {
+        GaussianInt::new(real, imaginary)
+    }
+}
+
+impl From<(i64, i64)> for GaussianInt {
+    fn from((real, imaginary): (i64, i64)) -> Self {
+        GaussianInt::new(BigInt::from(real), BigInt::from(imaginary))
+    }
+}
+
+impl std::fmt::Display for GaussianInt {
+    /// "3-4i", "5", "-i", "2+i"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.imaginary.is_zero() {
+            return std::fmt::Display::fmt(&self.real, f);
+        }
+
+        if !self.real.is_zero() {
+            std::fmt::Display::fmt(&self.real, f)?;
+            if self.imaginary.is_positive() {
+                write!(f, "+")?;
+            }
+        }
+
+        if self.imaginary.is_negative() {
+            write!(f, "-")?;
+        }
+        if !self.imaginary.natural.eq_u8(1) {
+            std::fmt::Display::fmt(&self.imaginary.natural, f)?;
+        }
+        write!(f, "i")
+    }
+}
+
+impl std::fmt::Debug for GaussianInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self, f)
+    }
+}
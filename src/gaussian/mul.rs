@@ -0,0 +1,23 @@
+use crate::GaussianInt;
+
+impl std::ops::Mul for &GaussianInt {
+    type Output = GaussianInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // (a + bi)(c + di) with three products, c(a + b), a(d - c) and b(c + d)
+        let (a, b) = (&self.real, &self.imaginary);
+        let (c, d) = (&rhs.real, &rhs.imaginary);
+        let k1 = c * &(a + b);
+        let k2 = a * &(d - c);
+        let k3 = b * &(c + d);
+        GaussianInt::new(&k1 - &k3, &k1 + &k2)
+    }
+}
+
+impl std::ops::Mul for GaussianInt {
+    type Output = GaussianInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        (&self).mul(&rhs)
+    }
+}
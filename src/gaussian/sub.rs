@@ -0,0 +1,17 @@
+use crate::GaussianInt;
+
+impl std::ops::Sub for &GaussianInt {
+    type Output = GaussianInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        GaussianInt::new(&self.real - &rhs.real, &self.imaginary - &rhs.imaginary)
+    }
+}
+
+impl std::ops::Sub for GaussianInt {
+    type Output = GaussianInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        (&self).sub(&rhs)
+    }
+}
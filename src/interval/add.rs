@@ -0,0 +1,29 @@
+use super::{precision, Endpoint};
+use crate::{BigFloat, BigRational, Interval, RoundingMode};
+
+macro_rules! impl_add {
+    ($($t:ty)*) => ($(
+        impl std::ops::Add for &Interval<$t> {
+            type Output = Interval<$t>;
+
+            /// [a; b] + [c; d] = [a + c; b + d]
+            fn add(self, rhs: Self) -> Self::Output {
+                let precision = precision(self, rhs);
+                Interval {
+                    lower: Endpoint::add(&self.lower, &rhs.lower, precision, RoundingMode::Floor),
+                    upper: Endpoint::add(&self.upper, &rhs.upper, precision, RoundingMode::Ceiling),
+                }
+            }
+        }
+
+        impl std::ops::Add for Interval<$t> {
+            type Output = Interval<$t>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                (&self).add(&rhs)
+            }
+        }
+    )*)
+}
+
+impl_add!(BigRational BigFloat);
@@ -0,0 +1,40 @@
+use super::{hull, precision, Endpoint};
+use crate::{APNum, BigFloat, BigRational, Interval, RoundingMode};
+
+macro_rules! impl_div {
+    ($($t:ty)*) => ($(
+        impl std::ops::Div for &Interval<$t> {
+            type Output = Interval<$t>;
+
+            /// The smallest and the largest of the quotients of the endpoints, rhs doesn't
+            /// contain zero
+            fn div(self, rhs: Self) -> Self::Output {
+                if rhs.contains(&<$t>::zero()) {
+                    panic!("Division by Zero");
+                }
+
+                let precision = precision(self, rhs);
+                let pairs = [
+                    (&self.lower, &rhs.lower),
+                    (&self.lower, &rhs.upper),
+                    (&self.upper, &rhs.lower),
+                    (&self.upper, &rhs.upper),
+                ];
+                let quotient = |mode| {
+                    pairs.map(|(x, y)| Endpoint::div(x, y, precision, mode)).into_iter()
+                };
+                hull(quotient(RoundingMode::Floor), quotient(RoundingMode::Ceiling))
+            }
+        }
+
+        impl std::ops::Div for Interval<$t> {
+            type Output = Interval<$t>;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                (&self).div(&rhs)
+            }
+        }
+    )*)
+}
+
+impl_div!(BigRational BigFloat);
@@ -0,0 +1,159 @@
+pub mod add;
+pub mod div;
+pub mod mul;
+pub mod root;
+pub mod sub;
+
+use crate::{BigFloat, BigRational, Interval, RoundingMode};
+
+/// Endpoint arithmetic rounded to precision bits in the given direction, exact for rationals
+pub(crate) trait Endpoint: Clone + Ord {
+    /// Precision of the results, the larger one of the operands for floats
+    fn precision(x: &Self, y: &Self) -> usize;
+    fn add(x: &Self, y: &Self, precision: usize, mode: RoundingMode) -> Self;
+    fn sub(x: &Self, y: &Self, precision: usize, mode: RoundingMode) -> Self;
+    fn mul(x: &Self, y: &Self, precision: usize, mode: RoundingMode) -> Self;
+    fn div(x: &Self, y: &Self, precision: usize, mode: RoundingMode) -> Self;
+    /// x / 2, exact
+    fn half(x: &Self) -> Self;
+}
+
+impl Endpoint for BigRational {
+    fn precision(_: &Self, _: &Self) -> usize {
+        0
+    }
+
+    fn add(x: &Self, y: &Self, _: usize, _: RoundingMode) -> Self {
+        x + y
+    }
+
+    fn sub(x: &Self, y: &Self, _: usize, _: RoundingMode) -> Self {
+        x - y
+    }
+
+    fn mul(x: &Self, y: &Self, _: usize, _: RoundingMode) -> Self {
+        x * y
+    }
+
+    fn div(x: &Self, y: &Self, _: usize, _: RoundingMode) -> Self {
+        x / y
+    }
+
+    fn half(x: &Self) -> Self {
+        BigRational::new(x.numerator.clone(), &x.denominator << 1)
+    }
+}
+
+impl Endpoint for BigFloat {
+    fn precision(x: &Self, y: &Self) -> usize {
+        x.precision.max(y.precision)
+    }
+
+    fn add(x: &Self, y: &Self, precision: usize, mode: RoundingMode) -> Self {
+        x.add_rounded(y, precision, mode)
+    }
+
+    fn sub(x: &Self, y: &Self, precision: usize, mode: RoundingMode) -> Self {
+        x.sub_rounded(y, precision, mode)
+    }
+
+    fn mul(x: &Self, y: &Self, precision: usize, mode: RoundingMode) -> Self {
+        x.mul_rounded(y, precision, mode)
+    }
+
+    fn div(x: &Self, y: &Self, precision: usize, mode: RoundingMode) -> Self {
+        x.div_rounded(y, precision, mode)
+    }
+
+    fn half(x: &Self) -> Self {
+        BigFloat::new(
+            x.mantissa.clone(),
+            x.exponent - 1,
+            x.precision,
+            RoundingMode::Down,
+        )
+    }
+}
+
+/// Precision of the results of an operation on the intervals x and y
+fn precision<T: Endpoint>(x: &Interval<T>, y: &Interval<T>) -> usize {
+    T::precision(&x.lower, &x.upper).max(T::precision(&y.lower, &y.upper))
+}
+
+/// The interval of the values, rounded down and up
+fn hull<T: Endpoint>(low: impl Iterator<Item = T>, high: impl Iterator<Item = T>) -> Interval<T> {
+    Interval {
+        lower: low.min().unwrap(),
+        upper: high.max().unwrap(),
+    }
+}
+
+impl<T: Clone + Ord> Interval<T> {
+    /// [lower; upper], with lower ≤ upper
+    pub fn new(lower: T, upper: T) -> Self {
+        if lower > upper {
+            panic!("Empty interval");
+        }
+        Interval { lower, upper }
+    }
+
+    /// [x; x]
+    pub fn point(x: T) -> Self {
+        Interval {
+            lower: x.clone(),
+            upper: x,
+        }
+    }
+
+    pub fn lower(&self) -> &T {
+        &self.lower
+    }
+
+    pub fn upper(&self) -> &T {
+        &self.upper
+    }
+
+    /// Whether lower ≤ x ≤ upper
+    pub fn contains(&self, x: &T) -> bool {
+        self.lower <= *x && *x <= self.upper
+    }
+
+    /// Whether other lies within self
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.lower <= other.lower && other.upper <= self.upper
+    }
+}
+
+macro_rules! impl_interval {
+    ($($t:ty)*) => ($(
+        impl Interval<$t> {
+            /// upper - lower, rounded up
+            pub fn width(&self) -> $t {
+                let precision = Endpoint::precision(&self.lower, &self.upper);
+                Endpoint::sub(&self.upper, &self.lower, precision, RoundingMode::Ceiling)
+            }
+
+            /// A point of the interval halfway between the endpoints, rounded to nearest
+            pub fn midpoint(&self) -> $t {
+                let precision = Endpoint::precision(&self.lower, &self.upper);
+                let sum = Endpoint::add(&self.lower, &self.upper, precision, RoundingMode::HalfEven);
+                <$t as Endpoint>::half(&sum)
+            }
+        }
+    )*)
+}
+
+impl_interval!(BigRational BigFloat);
+
+impl<T: std::fmt::Display> std::fmt::Display for Interval<T> {
+    /// "[lower, upper]"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.lower, self.upper)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Debug for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self, f)
+    }
+}
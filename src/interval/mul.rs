@@ -0,0 +1,35 @@
+use super::{hull, precision, Endpoint};
+use crate::{BigFloat, BigRational, Interval, RoundingMode};
+
+macro_rules! impl_mul {
+    ($($t:ty)*) => ($(
+        impl std::ops::Mul for &Interval<$t> {
+            type Output = Interval<$t>;
+
+            /// The smallest and the largest of the products of the endpoints
+            fn mul(self, rhs: Self) -> Self::Output {
+                let precision = precision(self, rhs);
+                let pairs = [
+                    (&self.lower, &rhs.lower),
+                    (&self.lower, &rhs.upper),
+                    (&self.upper, &rhs.lower),
+                    (&self.upper, &rhs.upper),
+                ];
+                let product = |mode| {
+                    pairs.map(|(x, y)| Endpoint::mul(x, y, precision, mode)).into_iter()
+                };
+                hull(product(RoundingMode::Floor), product(RoundingMode::Ceiling))
+            }
+        }
+
+        impl std::ops::Mul for Interval<$t> {
+            type Output = Interval<$t>;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                (&self).mul(&rhs)
+            }
+        }
+    )*)
+}
+
+impl_mul!(BigRational BigFloat);
@@ -0,0 +1,46 @@
+use crate::{APNum, BigFloat, BigInt, BigRational, Interval, RoundingMode};
+
+impl Interval<BigFloat> {
+    /// [√lower; √upper] rounded outward to the larger precision of the endpoints, the negative
+    /// part of the interval is dropped and only a negative upper endpoint panics
+    pub fn sqrt(&self) -> Self {
+        check_nonnegative(self.upper.is_negative());
+        let precision = self.lower.precision.max(self.upper.precision);
+        let lower = if self.lower.is_negative() {
+            BigFloat::new(BigInt::zero(), 0, precision, RoundingMode::Down)
+        } else {
+            self.lower.sqrt_rounded(precision, RoundingMode::Floor)
+        };
+        Interval {
+            lower,
+            upper: self.upper.sqrt_rounded(precision, RoundingMode::Ceiling),
+        }
+    }
+}
+
+impl Interval<BigRational> {
+    /// [√lower; √upper] rounded outward to dyadic rationals of precision significant bits, the
+    /// negative part of the interval is dropped and only a negative upper endpoint panics
+    pub fn sqrt(&self, precision: usize) -> Self {
+        check_nonnegative(self.upper.is_negative());
+        let root = |x: &BigRational, mode| {
+            let x = BigFloat::from_rational(x, precision, mode);
+            BigRational::from(&x.sqrt_rounded(precision, mode))
+        };
+        let lower = if self.lower.is_negative() {
+            BigRational::zero()
+        } else {
+            root(&self.lower, RoundingMode::Floor)
+        };
+        Interval {
+            lower,
+            upper: root(&self.upper, RoundingMode::Ceiling),
+        }
+    }
+}
+
+fn check_nonnegative(negative: bool) {
+    if negative {
+        panic!("Square root of a negative number");
+    }
+}
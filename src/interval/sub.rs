@@ -0,0 +1,29 @@
+use super::{precision, Endpoint};
+use crate::{BigFloat, BigRational, Interval, RoundingMode};
+
+macro_rules! impl_sub {
+    ($($t:ty)*) => ($(
+        impl std::ops::Sub for &Interval<$t> {
+            type Output = Interval<$t>;
+
+            /// [a; b] - [c; d] = [a - d; b - c]
+            fn sub(self, rhs: Self) -> Self::Output {
+                let precision = precision(self, rhs);
+                Interval {
+                    lower: Endpoint::sub(&self.lower, &rhs.upper, precision, RoundingMode::Floor),
+                    upper: Endpoint::sub(&self.upper, &rhs.lower, precision, RoundingMode::Ceiling),
+                }
+            }
+        }
+
+        impl std::ops::Sub for Interval<$t> {
+            type Output = Interval<$t>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                (&self).sub(&rhs)
+            }
+        }
+    )*)
+}
+
+impl_sub!(BigRational BigFloat);
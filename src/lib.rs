@@ -1,6 +1,22 @@
 // Aritrary Precision Numbers (APNum)
 mod bigint;
 mod bignat;
+mod bigrational;
+mod bigfloat;
+mod bigdecimal;
+mod rounding;
+mod gaussian;
+mod polynomial;
+mod matrix;
+mod interval;
+mod uint;
+pub mod factor;
+pub mod symbol;
+pub mod crt;
+pub mod sequences;
+pub mod constants;
+pub mod spigot;
+pub mod continued_fraction;
 
 pub type BigDigit = u32;
 pub type BiggerDigit = u64;
@@ -21,6 +37,86 @@ pub struct BigInt {
     pub(crate) natural: BigNat,
 }
 
+/// Arbitrary Precision Rational Number, in lowest terms with a nonzero denominator
+#[derive(PartialEq, Eq, Clone)]
+pub struct BigRational {
+    pub(crate) numerator: BigInt,
+    pub(crate) denominator: BigNat,
+}
+
+/// Arbitrary Precision Binary Floating Point Number, mantissa * 2^exponent,
+/// where the mantissa has at most precision bits
+#[derive(Clone)]
+pub struct BigFloat {
+    pub(crate) mantissa: BigInt,
+    pub(crate) exponent: i64,
+    pub(crate) precision: usize,
+}
+
+/// Arbitrary Precision Decimal Number, unscaled * 10^-scale
+#[derive(Clone)]
+pub struct BigDecimal {
+    pub(crate) unscaled: BigInt,
+    pub(crate) scale: i64,
+}
+
+/// Arbitrary Precision Gaussian Integer, real + imaginary * i
+#[derive(PartialEq, Eq, Clone)]
+pub struct GaussianInt {
+    pub(crate) real: BigInt,
+    pub(crate) imaginary: BigInt,
+}
+
+/// Univariate polynomial, coefficients from the constant term up, without trailing zeros
+#[derive(PartialEq, Eq, Clone)]
+pub struct Polynomial<T> {
+    pub(crate) coefficients: Vec<T>,
+}
+
+/// Dense matrix, entries in row-major order
+#[derive(PartialEq, Eq, Clone)]
+pub struct Matrix<T> {
+    pub(crate) rows: usize,
+    pub(crate) columns: usize,
+    pub(crate) entries: Vec<T>,
+}
+
+/// Closed interval [lower; upper] with exact endpoints, the operations round outward so that the
+/// result contains every value of the operation on the operands
+#[derive(PartialEq, Eq, Clone)]
+pub struct Interval<T> {
+    pub(crate) lower: T,
+    pub(crate) upper: T,
+}
+
+/// Fixed-width unsigned integer of LIMBS digits, in reverse order like BigNat, the operators wrap
+/// around modulo 2^(32 * LIMBS)
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Uint<const LIMBS: usize> {
+    pub(crate) limbs: [BigDigit; LIMBS],
+}
+
+/// 256-bit unsigned integer
+pub type U256 = Uint<8>;
+
+/// 512-bit unsigned integer
+pub type U512 = Uint<16>;
+
+/// Rounding of the inexact results
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum RoundingMode {
+    /// To the nearest, ties to the even neighbour
+    HalfEven,
+    /// To the nearest, ties away from zero
+    HalfUp,
+    /// Toward zero
+    Down,
+    /// Toward positive infinity
+    Ceiling,
+    /// Toward negative infinity
+    Floor,
+}
+
 #[derive(PartialEq, Eq, Clone)]
 pub(crate) enum Sign {
     Positive,
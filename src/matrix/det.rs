@@ -0,0 +1,65 @@
+use crate::{APNum, BigInt, Matrix};
+
+impl Matrix<BigInt> {
+    /// Determinant, by fraction-free elimination
+    pub fn determinant(&self) -> BigInt {
+        self.check_square();
+
+        let mut m = self.clone();
+        let (pivots, swaps) = m.echelon();
+        if pivots.len() < self.rows {
+            return BigInt::zero();
+        }
+
+        // The last pivot is the determinant up to the sign of the row swaps
+        let determinant = match self.rows {
+            0 => BigInt::from(1),
+            n => m[(n - 1, n - 1)].clone(),
+        };
+        if swaps % 2 == 1 {
+            -determinant
+        } else {
+            determinant
+        }
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().echelon().0.len()
+    }
+
+    /// Brings self to row echelon form in place and gives the pivot columns and the number of row
+    /// swaps. The entry (i, j) below the pivot row k - 1 becomes the minor of the pivot rows and
+    /// columns so far and row i and column j, so every division is exact.
+    // see. Bareiss, Sylvester's identity and multistep integer-preserving Gaussian elimination
+    // (1968)
+    pub(crate) fn echelon(&mut self) -> (Vec<usize>, usize) {
+        let mut pivots = vec![];
+        let mut swaps = 0;
+        let mut previous = BigInt::from(1);
+        for j in 0..self.columns {
+            let r = pivots.len();
+            if r == self.rows {
+                break;
+            }
+            let Some(p) = (r..self.rows).find(|&i| !self[(i, j)].is_zero()) else {
+                continue;
+            };
+            if p != r {
+                self.swap_rows(p, r);
+                swaps += 1;
+            }
+
+            // m(i, k) = (m(i, k) m(r, j) - m(i, j) m(r, k)) / previous pivot
+            for i in r + 1..self.rows {
+                for k in j + 1..self.columns {
+                    let value = &(&self[(i, k)] * &self[(r, j)]) - &(&self[(i, j)] * &self[(r, k)]);
+                    self[(i, k)] = (&value / &previous).0;
+                }
+                self[(i, j)] = BigInt::zero();
+            }
+            previous = self[(r, j)].clone();
+            pivots.push(j);
+        }
+        (pivots, swaps)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{APNum, BigInt, Matrix};
+
+impl Matrix<BigInt> {
+    /// Row Hermite normal form: the unique row echelon matrix with the same row lattice, positive
+    /// pivots and the entries above each pivot in [0; pivot), with the zero rows last
+    pub fn hermite_normal_form(&self) -> Self {
+        let mut h = self.clone();
+        let mut r = 0;
+        for j in 0..h.columns {
+            if r == h.rows {
+                break;
+            }
+
+            // Gather the gcd of the column in row r with unimodular row operations
+            for i in r + 1..h.rows {
+                if h[(i, j)].is_zero() {
+                    continue;
+                }
+                let (a, b) = (h[(r, j)].clone(), h[(i, j)].clone());
+                let (g, x, y) = extended_gcd(&a, &b);
+                let (u, v) = (-&(&b / &g).0, (&a / &g).0);
+                h.combine_rows(r, i, [[&x, &y], [&u, &v]]);
+            }
+            if h[(r, j)].is_zero() {
+                continue;
+            }
+            if h[(r, j)].is_negative() {
+                h.negate_row(r);
+            }
+
+            for i in 0..r {
+                let (q, _) = &h[(i, j)] / &h[(r, j)];
+                h.sub_row_multiple(i, r, &q);
+            }
+            r += 1;
+        }
+        h
+    }
+
+    /// (row i, row k) = (x row i + y row k, u row i + v row k)
+    pub(crate) fn combine_rows(&mut self, i: usize, k: usize, [[x, y], [u, v]]: [[&BigInt; 2]; 2]) {
+        for j in 0..self.columns {
+            let (a, b) = (&self[(i, j)], &self[(k, j)]);
+            let first = &(x * a) + &(y * b);
+            let second = &(u * a) + &(v * b);
+            self[(i, j)] = first;
+            self[(k, j)] = second;
+        }
+    }
+
+    /// row i -= q row k
+    pub(crate) fn sub_row_multiple(&mut self, i: usize, k: usize, q: &BigInt) {
+        if q.is_zero() {
+            return;
+        }
+        for j in 0..self.columns {
+            self[(i, j)] = &self[(i, j)] - &(q * &self[(k, j)]);
+        }
+    }
+
+    pub(crate) fn negate_row(&mut self, i: usize) {
+        for j in 0..self.columns {
+            self[(i, j)] = -&self[(i, j)];
+        }
+    }
+}
+
+/// (g, x, y) with g = gcd(a, b) ≥ 0 and x a + y b = g
+fn extended_gcd(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut x0, mut x1) = (BigInt::from(1), BigInt::zero());
+    let (mut y0, mut y1) = (BigInt::zero(), BigInt::from(1));
+    while !r1.is_zero() {
+        let (q, r) = &r0 / &r1;
+        (r0, r1) = (r1, r);
+        (x0, x1) = (x1.clone(), &x0 - &(&q * &x1));
+        (y0, y1) = (y1.clone(), &y0 - &(&q * &y1));
+    }
+    if r0.is_negative() {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
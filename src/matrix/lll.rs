@@ -0,0 +1,140 @@
+use crate::{APNum, BigInt, BigNat, BigRational, Matrix};
+
+impl Matrix<BigInt> {
+    /// LLL-reduced basis of the lattice spanned by the rows, with δ = 3/4
+    pub fn lll(&self) -> Self {
+        self.lll_with_delta(&BigRational::new(BigInt::from(3), BigNat::from(4usize)))
+    }
+
+    /// LLL-reduced basis of the lattice spanned by the rows, which are linearly independent,
+    /// with the Lovász condition parameter 1/4 < δ ≤ 1. The Gram–Schmidt coefficients are kept
+    /// as the integers d(i) = det of the Gram matrix of the first i rows and
+    /// λ(i, j) = d(j + 1) μ(i, j), so the whole reduction is exact.
+    // see. Cohen, A Course in Computational Algebraic Number Theory, Algorithm 2.6.7
+    pub fn lll_with_delta(&self, delta: &BigRational) -> Self {
+        let quarter = BigRational::new(BigInt::from(1), BigNat::from(4usize));
+        if *delta <= quarter || *delta > BigRational::from(1) {
+            panic!("LLL parameter out of range");
+        }
+
+        let mut lll = Lll {
+            b: self.clone(),
+            d: vec![BigInt::from(1); self.rows + 1],
+            lambda: Matrix::zero(self.rows, self.rows),
+        };
+        if self.rows == 0 {
+            return lll.b;
+        }
+
+        lll.d[1] = dot(lll.b.row(0), lll.b.row(0));
+        lll.check_independent(1);
+        let (p, q) = (
+            delta.numerator(),
+            &BigInt::from(delta.denominator().clone()),
+        );
+
+        // Rows 0..=k_max have their Gram–Schmidt data computed
+        let (mut k, mut k_max) = (1, 0);
+        while k < self.rows {
+            if k > k_max {
+                k_max = k;
+                lll.gram_schmidt(k);
+            }
+
+            lll.reduce(k, k - 1);
+
+            // Lovász condition, q d(k + 1) d(k - 1) ≥ p d(k)^2 - q λ(k, k - 1)^2
+            let left = &(q * &lll.d[k + 1]) * &lll.d[k - 1];
+            let lambda = &lll.lambda[(k, k - 1)];
+            let right = &(&(p * &lll.d[k]) * &lll.d[k]) - &(&(q * lambda) * lambda);
+            if left < right {
+                lll.swap(k, k_max);
+                k = (k - 1).max(1);
+                continue;
+            }
+
+            for l in (0..k - 1).rev() {
+                lll.reduce(k, l);
+            }
+            k += 1;
+        }
+        lll.b
+    }
+}
+
+/// State of the integral LLL reduction, with d(i) for the first i rows
+struct Lll {
+    b: Matrix<BigInt>,
+    d: Vec<BigInt>,
+    lambda: Matrix<BigInt>,
+}
+
+impl Lll {
+    /// λ(k, j) for j < k and d(k + 1)
+    fn gram_schmidt(&mut self, k: usize) {
+        for j in 0..=k {
+            let mut u = dot(self.b.row(k), self.b.row(j));
+            for i in 0..j {
+                let value = &(&self.d[i + 1] * &u) - &(&self.lambda[(k, i)] * &self.lambda[(j, i)]);
+                u = (&value / &self.d[i]).0;
+            }
+            if j < k {
+                self.lambda[(k, j)] = u;
+            } else {
+                self.d[k + 1] = u;
+            }
+        }
+        self.check_independent(k + 1);
+    }
+
+    fn check_independent(&self, i: usize) {
+        if self.d[i].is_zero() {
+            panic!("Linearly dependent rows");
+        }
+    }
+
+    /// Size reduction of row k against row l < k, |μ(k, l)| ≤ 1/2 afterwards
+    fn reduce(&mut self, k: usize, l: usize) {
+        let d = &self.d[l + 1];
+        let lambda = &self.lambda[(k, l)];
+        if &lambda.natural + &lambda.natural <= d.natural {
+            return;
+        }
+
+        // The integer nearest to λ(k, l) / d(l + 1)
+        let twice = d + d;
+        let (r, _) = &(&(lambda + lambda) + d) / &twice;
+        self.b.sub_row_multiple(k, l, &r);
+        self.lambda[(k, l)] = lambda - &(&r * d);
+        for i in 0..l {
+            self.lambda[(k, i)] = &self.lambda[(k, i)] - &(&r * &self.lambda[(l, i)]);
+        }
+    }
+
+    /// Exchanges the rows k - 1 and k, updating the Gram–Schmidt data of the rows up to k_max
+    fn swap(&mut self, k: usize, k_max: usize) {
+        self.b.swap_rows(k, k - 1);
+        for j in 0..k - 1 {
+            let (x, y) = (self.lambda[(k, j)].clone(), self.lambda[(k - 1, j)].clone());
+            self.lambda[(k, j)] = y;
+            self.lambda[(k - 1, j)] = x;
+        }
+
+        // The new d(k) is B = (d(k - 1) d(k + 1) + λ^2) / d(k)
+        let lambda = self.lambda[(k, k - 1)].clone();
+        let (d0, d1, d2) = (&self.d[k - 1], &self.d[k], &self.d[k + 1]);
+        let b = (&(&(d0 * d2) + &(&lambda * &lambda)) / d1).0;
+        for i in k + 1..=k_max {
+            let t = self.lambda[(i, k)].clone();
+            let value = &(d2 * &self.lambda[(i, k - 1)]) - &(&lambda * &t);
+            self.lambda[(i, k)] = (&value / d1).0;
+            let value = &(&b * &t) + &(&lambda * &self.lambda[(i, k)]);
+            self.lambda[(i, k - 1)] = (&value / d2).0;
+        }
+        self.d[k] = b;
+    }
+}
+
+fn dot(x: &[BigInt], y: &[BigInt]) -> BigInt {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
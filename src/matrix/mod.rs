@@ -0,0 +1,146 @@
+pub mod add;
+pub mod det;
+pub mod hermite;
+pub mod lll;
+pub mod mul;
+pub mod smith;
+pub mod solve;
+pub mod sub;
+
+use crate::{APNum, BigInt, Matrix};
+
+impl<T: APNum + Clone> Matrix<T> {
+    /// Matrix with the given entries in row-major order
+    pub fn new(rows: usize, columns: usize, entries: Vec<T>) -> Self {
+        if entries.len() != rows * columns {
+            panic!("Dimension mismatch");
+        }
+        Matrix {
+            rows,
+            columns,
+            entries,
+        }
+    }
+
+    /// Matrix from its rows, which have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let columns = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != columns) {
+            panic!("Dimension mismatch");
+        }
+        Matrix {
+            rows: rows.len(),
+            columns,
+            entries: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn zero(rows: usize, columns: usize) -> Self {
+        Matrix {
+            rows,
+            columns,
+            entries: vec![T::zero(); rows * columns],
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.entries[i * self.columns..(i + 1) * self.columns]
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    pub fn transpose(&self) -> Self {
+        let entries = (0..self.columns)
+            .flat_map(|j| (0..self.rows).map(move |i| (i, j)))
+            .map(|index| self[index].clone())
+            .collect();
+        Matrix {
+            rows: self.columns,
+            columns: self.rows,
+            entries,
+        }
+    }
+
+    pub(crate) fn swap_rows(&mut self, i: usize, k: usize) {
+        for j in 0..self.columns {
+            self.entries
+                .swap(i * self.columns + j, k * self.columns + j);
+        }
+    }
+
+    pub(crate) fn swap_columns(&mut self, j: usize, k: usize) {
+        for i in 0..self.rows {
+            self.entries
+                .swap(i * self.columns + j, i * self.columns + k);
+        }
+    }
+
+    pub(crate) fn check_square(&self) {
+        if !self.is_square() {
+            panic!("Non-square matrix");
+        }
+    }
+}
+
+impl Matrix<BigInt> {
+    pub fn identity(n: usize) -> Self {
+        let mut identity = Matrix::zero(n, n);
+        for i in 0..n {
+            identity[(i, i)] = BigInt::from(1);
+        }
+        identity
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        assert!(i < self.rows && j < self.columns, "Index out of bounds");
+        &self.entries[i * self.columns + j]
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        assert!(i < self.rows && j < self.columns, "Index out of bounds");
+        &mut self.entries[i * self.columns + j]
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Matrix<T> {
+    /// "[[1, 2], [3, 4]]"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[")?;
+        for i in 0..self.rows {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "[")?;
+            for j in 0..self.columns {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                std::fmt::Display::fmt(&self.entries[i * self.columns + j], f)?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Debug for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self, f)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{APNum, BigInt, Matrix};
+
+impl std::ops::Mul for &Matrix<BigInt> {
+    type Output = Matrix<BigInt>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.columns != rhs.rows {
+            panic!("Dimension mismatch");
+        }
+
+        let mut product = Matrix::zero(self.rows, rhs.columns);
+        for i in 0..self.rows {
+            for k in 0..self.columns {
+                let x = &self[(i, k)];
+                if x.is_zero() {
+                    continue;
+                }
+                for j in 0..rhs.columns {
+                    product[(i, j)] = &product[(i, j)] + &(x * &rhs[(k, j)]);
+                }
+            }
+        }
+        product
+    }
+}
+
+impl std::ops::Mul for Matrix<BigInt> {
+    type Output = Matrix<BigInt>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        (&self).mul(&rhs)
+    }
+}
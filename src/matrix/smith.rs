@@ -0,0 +1,62 @@
+use crate::{APNum, BigInt, Matrix};
+
+impl Matrix<BigInt> {
+    /// Smith normal form: the diagonal matrix equivalent to self under unimodular row and column
+    /// operations, with nonnegative entries each dividing the next
+    pub fn smith_normal_form(&self) -> Self {
+        let mut d = self.clone();
+        for t in 0..d.rows.min(d.columns) {
+            // The smallest entry goes to (t, t) and reduces its row and column, until both are
+            // zero and it divides the rest; its absolute value decreases every round
+            loop {
+                let entries = (t..d.rows).flat_map(|i| (t..d.columns).map(move |j| (i, j)));
+                let Some((i, j)) = entries
+                    .filter(|&index| !d[index].is_zero())
+                    .min_by(|&x, &y| d[x].natural.cmp(&d[y].natural))
+                else {
+                    return d;
+                };
+                d.swap_rows(t, i);
+                d.swap_columns(t, j);
+
+                let mut reduced = true;
+                for i in t + 1..d.rows {
+                    let (q, _) = &d[(i, t)] / &d[(t, t)];
+                    d.sub_row_multiple(i, t, &q);
+                    reduced &= d[(i, t)].is_zero();
+                }
+                for j in t + 1..d.columns {
+                    let (q, _) = &d[(t, j)] / &d[(t, t)];
+                    d.sub_column_multiple(j, t, &q);
+                    reduced &= d[(t, j)].is_zero();
+                }
+                if !reduced {
+                    continue;
+                }
+
+                // Adding a row with an entry not divisible by the pivot leaves a smaller remainder
+                let pivot = &d[(t, t)];
+                let rest = (t + 1..d.rows)
+                    .find(|&i| (t + 1..d.columns).any(|j| !(&d[(i, j)] / pivot).1.is_zero()));
+                match rest {
+                    Some(i) => d.sub_row_multiple(t, i, &BigInt::from(-1)),
+                    None => break,
+                }
+            }
+            if d[(t, t)].is_negative() {
+                d.negate_row(t);
+            }
+        }
+        d
+    }
+
+    /// column j -= q column k
+    fn sub_column_multiple(&mut self, j: usize, k: usize, q: &BigInt) {
+        if q.is_zero() {
+            return;
+        }
+        for i in 0..self.rows {
+            self[(i, j)] = &self[(i, j)] - &(q * &self[(i, k)]);
+        }
+    }
+}
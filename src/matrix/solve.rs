@@ -0,0 +1,34 @@
+use crate::{APNum, BigInt, BigRational, Matrix};
+
+impl Matrix<BigInt> {
+    /// A rational solution x of self x = b, with the free variables zero, None when there is none
+    pub fn solve(&self, b: &[BigInt]) -> Option<Vec<BigRational>> {
+        if b.len() != self.rows {
+            panic!("Dimension mismatch");
+        }
+
+        // Elimination on [self | b], the system is inconsistent when b has a pivot
+        let n = self.columns;
+        let entries = (0..self.rows)
+            .flat_map(|i| self.row(i).iter().chain([&b[i]]).cloned())
+            .collect();
+        let mut m = Matrix::new(self.rows, n + 1, entries);
+        let (pivots, _) = m.echelon();
+        if pivots.last() == Some(&n) {
+            return None;
+        }
+
+        // x(j) = (b(r) - Σ m(r, k) x(k)) / m(r, j) for the pivot (r, j), from the last one up
+        let mut x = vec![BigRational::zero(); n];
+        for (r, &j) in pivots.iter().enumerate().rev() {
+            let mut value = BigRational::from(m[(r, n)].clone());
+            for (k, xk) in x.iter().enumerate().skip(j + 1) {
+                if !xk.is_zero() {
+                    value = &value - &(&BigRational::from(m[(r, k)].clone()) * xk);
+                }
+            }
+            x[j] = &value / &BigRational::from(m[(r, j)].clone());
+        }
+        Some(x)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{BigInt, Matrix};
+
+macro_rules! impl_sub {
+    ($($t:ty)*) => ($(
+        impl std::ops::Sub for &Matrix<$t> {
+            type Output = Matrix<$t>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                if self.rows != rhs.rows || self.columns != rhs.columns {
+                    panic!("Dimension mismatch");
+                }
+
+                let entries = self.entries.iter().zip(&rhs.entries).map(|(x, y)| x - y).collect();
+                Matrix {
+                    rows: self.rows,
+                    columns: self.columns,
+                    entries,
+                }
+            }
+        }
+
+        impl std::ops::Sub for Matrix<$t> {
+            type Output = Matrix<$t>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                (&self).sub(&rhs)
+            }
+        }
+    )*)
+}
+
+impl_sub!(BigInt);
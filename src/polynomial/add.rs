@@ -0,0 +1,33 @@
+use crate::{BigInt, BigRational, Polynomial};
+
+macro_rules! impl_add {
+    ($($t:ty)*) => ($(
+        impl std::ops::Add for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                let (long, short) = if self.coefficients.len() >= rhs.coefficients.len() {
+                    (self, rhs)
+                } else {
+                    (rhs, self)
+                };
+
+                let mut coefficients = long.coefficients.clone();
+                for (x, y) in coefficients.iter_mut().zip(&short.coefficients) {
+                    *x = &*x + y;
+                }
+                Polynomial::new(coefficients)
+            }
+        }
+
+        impl std::ops::Add for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                (&self).add(&rhs)
+            }
+        }
+    )*)
+}
+
+impl_add!(BigInt BigRational);
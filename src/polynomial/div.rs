@@ -0,0 +1,84 @@
+use crate::{APNum, BigInt, BigRational, Polynomial};
+
+impl Polynomial<BigInt> {
+    /// (q, r) with lc(divisor)^(deg self - deg divisor + 1) * self = q * divisor + r and
+    /// deg r < deg divisor, (0, self) when self has the smaller degree
+    // see. Cohen, A Course in Computational Algebraic Number Theory, Algorithm 3.1.2
+    pub fn pseudo_div(&self, divisor: &Polynomial<BigInt>) -> (Self, Self) {
+        let Some(n) = divisor.degree() else {
+            panic!("Division by Zero");
+        };
+        let Some(m) = self.degree().filter(|&m| m >= n) else {
+            return (Polynomial::zero(), self.clone());
+        };
+
+        let d = &divisor.coefficients[n];
+        let mut q = vec![BigInt::zero(); m - n + 1];
+        let mut r = self.coefficients.clone();
+        let mut e = m - n + 1;
+        while r.len() > n {
+            // r = d r - lc(r) x^k divisor, q = d q + lc(r) x^k
+            let k = r.len() - 1 - n;
+            let s = r.pop().unwrap();
+            for c in q.iter_mut().chain(r.iter_mut()) {
+                *c = &*c * d;
+            }
+            for (c, b) in r[k..].iter_mut().zip(&divisor.coefficients) {
+                *c = &*c - &(&s * b);
+            }
+            q[k] = s;
+            while r.last().is_some_and(|c| c.is_zero()) {
+                r.pop();
+            }
+            e -= 1;
+        }
+
+        let power = d.pow(e as u32);
+        let q = Polynomial::new(q.iter().map(|c| c * &power).collect());
+        let r = Polynomial::new(r.iter().map(|c| c * &power).collect());
+        (q, r)
+    }
+
+    /// Remainder of the pseudo-division
+    pub fn pseudo_rem(&self, divisor: &Polynomial<BigInt>) -> Self {
+        self.pseudo_div(divisor).1
+    }
+}
+
+impl std::ops::Div for &Polynomial<BigRational> {
+    type Output = (Polynomial<BigRational>, Polynomial<BigRational>);
+
+    /// (q, r) with self = q * rhs + r and deg r < deg rhs
+    fn div(self, rhs: Self) -> Self::Output {
+        let Some(n) = rhs.degree() else {
+            panic!("Division by Zero");
+        };
+        let Some(m) = self.degree().filter(|&m| m >= n) else {
+            return (Polynomial::zero(), self.clone());
+        };
+
+        let inverse = rhs.coefficients[n].recip();
+        let mut q = vec![BigRational::zero(); m - n + 1];
+        let mut r = self.coefficients.clone();
+        while r.len() > n {
+            let k = r.len() - 1 - n;
+            let s = &r.pop().unwrap() * &inverse;
+            for (c, b) in r[k..].iter_mut().zip(&rhs.coefficients) {
+                *c = &*c - &(&s * b);
+            }
+            q[k] = s;
+            while r.last().is_some_and(|c| c.is_zero()) {
+                r.pop();
+            }
+        }
+        (Polynomial::new(q), Polynomial::new(r))
+    }
+}
+
+impl std::ops::Div for Polynomial<BigRational> {
+    type Output = (Polynomial<BigRational>, Polynomial<BigRational>);
+
+    fn div(self, rhs: Self) -> Self::Output {
+        (&self).div(&rhs)
+    }
+}
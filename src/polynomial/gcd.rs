@@ -0,0 +1,56 @@
+use crate::{APNum, BigInt, BigRational, Polynomial};
+
+impl Polynomial<BigInt> {
+    /// Greatest common divisor with a positive leading coefficient, by the subresultant
+    /// algorithm, which keeps the coefficients of the remainders small without computing their
+    /// contents
+    // see. Cohen, A Course in Computational Algebraic Number Theory, Algorithm 3.3.1
+    pub fn gcd(&self, other: &Polynomial<BigInt>) -> Self {
+        let (mut a, mut b) = if self.degree() >= other.degree() {
+            (self.clone(), other.clone())
+        } else {
+            (other.clone(), self.clone())
+        };
+        if b.is_zero() {
+            return a.primitive_part().scale(&BigInt::from(a.content()));
+        }
+
+        let d = BigInt::from(a.content().gcd(&b.content()));
+        a = a.primitive_part();
+        b = b.primitive_part();
+        let (mut g, mut h) = (BigInt::from(1), BigInt::from(1));
+        loop {
+            let delta = (a.degree().unwrap() - b.degree().unwrap()) as u32;
+            let r = a.pseudo_rem(&b);
+            match r.degree() {
+                None => break,
+                Some(0) => {
+                    b = Polynomial::new(vec![BigInt::from(1)]);
+                    break;
+                }
+                Some(_) => {}
+            }
+
+            // b = r / (g h^delta), then h = g^delta / h^(delta - 1)
+            a = b;
+            b = r.exact_div(&(&g * &h.pow(delta)));
+            g = a.leading_coefficient().unwrap().clone();
+            h = if delta == 0 {
+                h
+            } else {
+                (&g.pow(delta) / &h.pow(delta - 1)).0
+            };
+        }
+        b.primitive_part().scale(&d)
+    }
+}
+
+impl Polynomial<BigRational> {
+    /// Monic greatest common divisor, zero when both are zero
+    pub fn gcd(&self, other: &Polynomial<BigRational>) -> Self {
+        // Denominators are units, the gcd over the integers has the same roots
+        let (a, _) = self.integral();
+        let (b, _) = other.integral();
+        Polynomial::from(&a.gcd(&b)).monic()
+    }
+}
@@ -0,0 +1,229 @@
+pub mod add;
+pub mod div;
+pub mod gcd;
+pub mod mul;
+pub mod roots;
+pub mod sub;
+
+use crate::{APNum, BigInt, BigNat, BigRational, Polynomial};
+
+impl<T: APNum> Polynomial<T> {
+    /// Polynomial with the coefficients from the constant term up
+    pub fn new(coefficients: Vec<T>) -> Self {
+        Polynomial { coefficients }.zero_normalized()
+    }
+
+    /// c * x^degree
+    pub fn monomial(c: T, degree: usize) -> Self {
+        let mut coefficients: Vec<T> = (0..degree).map(|_| T::zero()).collect();
+        coefficients.push(c);
+        Polynomial::new(coefficients)
+    }
+
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// None for the zero polynomial
+    pub fn leading_coefficient(&self) -> Option<&T> {
+        self.coefficients.last()
+    }
+
+    /// Value at x, by Horner's rule
+    pub fn evaluate(&self, x: &T) -> T
+    where
+        for<'a> &'a T: std::ops::Add<&'a T, Output = T> + std::ops::Mul<&'a T, Output = T>,
+    {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, c| &(&acc * x) + c)
+    }
+
+    pub fn derivative(&self) -> Self
+    where
+        T: From<u64>,
+        for<'a> &'a T: std::ops::Mul<&'a T, Output = T>,
+    {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| c * &T::from(i as u64))
+            .collect();
+        Polynomial::new(coefficients)
+    }
+}
+
+impl Polynomial<BigInt> {
+    /// Greatest common divisor of the coefficients, zero for the zero polynomial
+    pub fn content(&self) -> BigNat {
+        self.coefficients
+            .iter()
+            .fold(BigNat::zero(), |acc, c| acc.gcd(&c.natural))
+    }
+
+    /// self / content, with a positive leading coefficient
+    pub fn primitive_part(&self) -> Self {
+        let content = BigInt::from(self.content());
+        let content = match self.leading_coefficient() {
+            Some(c) if c.is_negative() => -content,
+            Some(_) => content,
+            None => return self.clone(),
+        };
+        self.exact_div(&content)
+    }
+
+    /// self / c for c dividing every coefficient
+    pub(crate) fn exact_div(&self, c: &BigInt) -> Self {
+        let coefficients = self.coefficients.iter().map(|x| (x / c).0).collect();
+        Polynomial { coefficients }
+    }
+
+    /// self * c
+    pub(crate) fn scale(&self, c: &BigInt) -> Self {
+        Polynomial::new(self.coefficients.iter().map(|x| x * c).collect())
+    }
+}
+
+impl Polynomial<BigRational> {
+    /// (p, d) with self = p / d, d the least common multiple of the denominators
+    pub(crate) fn integral(&self) -> (Polynomial<BigInt>, BigNat) {
+        let d = self
+            .coefficients
+            .iter()
+            .fold(BigNat::from(1usize), |acc, c| acc.lcm(&c.denominator));
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|c| {
+                let (factor, _) = &d / &c.denominator;
+                &c.numerator * &BigInt::from(factor)
+            })
+            .collect();
+        (Polynomial { coefficients }, d)
+    }
+
+    /// self divided by its leading coefficient
+    pub fn monic(&self) -> Self {
+        match self.leading_coefficient() {
+            Some(c) => {
+                let inverse = c.recip();
+                Polynomial::new(self.coefficients.iter().map(|x| x * &inverse).collect())
+            }
+            None => self.clone(),
+        }
+    }
+}
+
+impl From<&Polynomial<BigInt>> for Polynomial<BigRational> {
+    fn from(value: &Polynomial<BigInt>) -> Self {
+        let coefficients = value
+            .coefficients
+            .iter()
+            .map(|c| BigRational::from(c.clone()))
+            .collect();
+        Polynomial { coefficients }
+    }
+}
+
+impl<T: APNum> APNum for Polynomial<T> {
+    fn zero() -> Self {
+        Polynomial {
+            coefficients: vec![],
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    fn zero_normalized(mut self) -> Self {
+        while self.coefficients.last().is_some_and(|c| c.is_zero()) {
+            self.coefficients.pop();
+        }
+        self
+    }
+
+    fn digit_count(&self) -> usize {
+        self.coefficients.iter().map(|c| c.digit_count()).sum()
+    }
+}
+
+macro_rules! impl_neg {
+    ($($t:ty)*) => ($(
+        impl std::ops::Neg for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            fn neg(self) -> Self::Output {
+                Polynomial {
+                    coefficients: self.coefficients.iter().map(|c| -c).collect(),
+                }
+            }
+        }
+
+        impl std::ops::Neg for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            fn neg(self) -> Self::Output {
+                -(&self)
+            }
+        }
+    )*)
+}
+
+impl_neg!(BigInt BigRational);
+
+impl<T: APNum + std::fmt::Display> std::fmt::Display for Polynomial<T> {
+    /// "3*x^2 - x + 1/2", highest degree first
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut first = true;
+        for (degree, c) in self.coefficients.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+
+            let c = c.to_string();
+            let (negative, magnitude) = match c.strip_prefix('-') {
+                Some(magnitude) => (true, magnitude),
+                None => (false, c.as_str()),
+            };
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+
+            if degree == 0 {
+                write!(f, "{magnitude}")?;
+                continue;
+            }
+            if magnitude != "1" {
+                write!(f, "{magnitude}*")?;
+            }
+            match degree {
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{degree}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: APNum + std::fmt::Display> std::fmt::Debug for Polynomial<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self, f)
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{APNum, BigDigit, BigInt, BigNat, BigRational, Polynomial, Sign};
+
+impl std::ops::Mul for &Polynomial<BigInt> {
+    type Output = Polynomial<BigInt>;
+
+    /// Kronecker substitution: both polynomials are evaluated at 2^k, with k large enough for the
+    /// coefficients of the product, and the product of the values is read back in base 2^k
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+
+        // |coefficients of the product| < terms * 2^(bits of self + bits of rhs), plus a sign bit,
+        // rounded up to whole digits
+        let bits = |p: &Polynomial<BigInt>| p.coefficients.iter().map(|c| c.natural.bits()).max();
+        let terms = self.coefficients.len().min(rhs.coefficients.len());
+        let needed = bits(self).unwrap() + bits(rhs).unwrap() + terms.ilog2() as usize + 2;
+        let width = needed.div_ceil(BigDigit::BITS as usize);
+
+        let product = &pack(self, width) * &pack(rhs, width);
+        let length = self.coefficients.len() + rhs.coefficients.len() - 1;
+        Polynomial::new(unpack(&product, width, length))
+    }
+}
+
+impl std::ops::Mul for Polynomial<BigInt> {
+    type Output = Polynomial<BigInt>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        (&self).mul(&rhs)
+    }
+}
+
+impl std::ops::Mul for &Polynomial<BigRational> {
+    type Output = Polynomial<BigRational>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // p / a * q / b = pq / ab
+        let (p, a) = self.integral();
+        let (q, b) = rhs.integral();
+        let denominator = &a * &b;
+        let coefficients = (&p * &q)
+            .coefficients
+            .into_iter()
+            .map(|c| BigRational::new(c, denominator.clone()))
+            .collect();
+        Polynomial::new(coefficients)
+    }
+}
+
+impl std::ops::Mul for Polynomial<BigRational> {
+    type Output = Polynomial<BigRational>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        (&self).mul(&rhs)
+    }
+}
+
+/// p(2^(32 width)), as the difference of the positive and the negative coefficients laid out
+/// digit by digit
+fn pack(p: &Polynomial<BigInt>, width: usize) -> BigInt {
+    let length = p.coefficients.len() * width;
+    let (mut positive, mut negative) = (vec![0; length], vec![0; length]);
+    for (i, c) in p.coefficients.iter().enumerate() {
+        let digits = match c.sign {
+            Sign::Positive => &mut positive,
+            Sign::Negative => &mut negative,
+            Sign::Zero => continue,
+        };
+        let offset = i * width;
+        digits[offset..offset + c.natural.digits.len()].copy_from_slice(&c.natural.digits);
+    }
+
+    let positive = BigNat { digits: positive }.zero_normalized();
+    let negative = BigNat { digits: negative }.zero_normalized();
+    &positive - &negative
+}
+
+/// The coefficients of the value in base 2^(32 width), with digits in [-2^(32 width - 1);
+/// 2^(32 width - 1))
+fn unpack(value: &BigInt, width: usize, length: usize) -> Vec<BigInt> {
+    let half = BigNat::from(1usize) << (BigDigit::BITS as usize * width - 1);
+    let full = &half << 1;
+
+    let mut coefficients = Vec::with_capacity(length);
+    let mut carry = false;
+    for i in 0..length {
+        let start = (i * width).min(value.natural.digits.len());
+        let end = ((i + 1) * width).min(value.natural.digits.len());
+        let mut digit = BigNat {
+            digits: value.natural.digits[start..end].to_vec(),
+        }
+        .zero_normalized();
+        if carry {
+            digit = &digit + 1u8;
+        }
+
+        // A digit of 2^(32 width) only comes from the carry, and is a zero with a carry
+        carry = digit >= half;
+        let digit = if carry {
+            -(&full - &digit)
+        } else {
+            BigInt::from(digit)
+        };
+
+        let digit = match value.sign {
+            Sign::Negative => -digit,
+            _ => digit,
+        };
+        coefficients.push(digit);
+    }
+    coefficients
+}
@@ -0,0 +1,48 @@
+use crate::factor::factor;
+use crate::{APNum, BigInt, BigNat, Polynomial};
+
+impl Polynomial<BigInt> {
+    /// Distinct integer roots in increasing order
+    pub fn integer_roots(&self) -> Vec<BigInt> {
+        if self.is_zero() {
+            panic!("Roots of the zero polynomial");
+        }
+
+        // x^k p(x) with p(0) nonzero, and 0 is a root when k > 0
+        let k = self.coefficients.iter().take_while(|c| c.is_zero()).count();
+        let mut roots = vec![];
+        if k > 0 {
+            roots.push(BigInt::zero());
+        }
+
+        // The other roots divide p(0)
+        let p = Polynomial::new(self.coefficients[k..].to_vec());
+        for d in divisors(&p.coefficients[0].natural) {
+            let d = BigInt::from(d);
+            for r in [-&d, d] {
+                if p.evaluate(&r).is_zero() {
+                    roots.push(r);
+                }
+            }
+        }
+        roots.sort();
+        roots
+    }
+}
+
+/// Divisors of the nonzero n
+fn divisors(n: &BigNat) -> Vec<BigNat> {
+    let mut divisors = vec![BigNat::from(1usize)];
+    for (prime, exponent) in factor(n) {
+        let mut powers = vec![];
+        for d in &divisors {
+            let mut power = d.clone();
+            for _ in 0..exponent {
+                power = &power * &prime;
+                powers.push(power.clone());
+            }
+        }
+        divisors.extend(powers);
+    }
+    divisors
+}
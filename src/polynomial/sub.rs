@@ -0,0 +1,32 @@
+use crate::{BigInt, BigRational, Polynomial};
+
+macro_rules! impl_sub {
+    ($($t:ty)*) => ($(
+        impl std::ops::Sub for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                let length = self.coefficients.len().max(rhs.coefficients.len());
+                let coefficients = (0..length)
+                    .map(|i| match (self.coefficients.get(i), rhs.coefficients.get(i)) {
+                        (Some(x), Some(y)) => x - y,
+                        (Some(x), None) => x.clone(),
+                        (None, Some(y)) => -y,
+                        (None, None) => unreachable!(),
+                    })
+                    .collect();
+                Polynomial::new(coefficients)
+            }
+        }
+
+        impl std::ops::Sub for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                (&self).sub(&rhs)
+            }
+        }
+    )*)
+}
+
+impl_sub!(BigInt BigRational);
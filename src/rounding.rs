@@ -0,0 +1,75 @@
+// Rounding shared by the floating point and the decimal types
+
+use std::cmp::Ordering;
+
+use crate::{APNum, BigNat, RoundingMode};
+
+impl RoundingMode {
+    /// Whether the magnitude truncated toward zero has to be incremented, given the sign of the
+    /// value, the parity of the truncated magnitude and how the discarded part compares to one
+    /// half (None if nothing was discarded)
+    pub(crate) fn round_up(self, negative: bool, odd: bool, discarded: Option<Ordering>) -> bool {
+        let Some(discarded) = discarded else {
+            return false;
+        };
+
+        match self {
+            RoundingMode::HalfEven => discarded.is_gt() || (discarded.is_eq() && odd),
+            RoundingMode::HalfUp => discarded.is_ge(),
+            RoundingMode::Down => false,
+            RoundingMode::Ceiling => !negative,
+            RoundingMode::Floor => negative,
+        }
+    }
+}
+
+/// numerator / denominator rounded to an integer, the magnitudes of a value of the given sign
+pub(crate) fn div_rounded(
+    numerator: &BigNat,
+    denominator: &BigNat,
+    negative: bool,
+    mode: RoundingMode,
+) -> BigNat {
+    let (quotient, remainder) = numerator / denominator;
+    let discarded = (!remainder.is_zero()).then(|| (&remainder << 1).cmp(denominator));
+    if mode.round_up(negative, !quotient.is_even(), discarded) {
+        &quotient + 1u8
+    } else {
+        quotient
+    }
+}
+
+/// magnitude / 2^shift rounded to an integer, the magnitude of a value of the given sign.
+/// sticky tells that the actual magnitude is a little more than the given one (less than
+/// its last unit), shift has to be positive then.
+pub(crate) fn shr_rounded(
+    magnitude: &BigNat,
+    shift: usize,
+    sticky: bool,
+    negative: bool,
+    mode: RoundingMode,
+) -> BigNat {
+    if shift == 0 {
+        debug_assert!(!sticky);
+        return magnitude.clone();
+    }
+
+    let quotient = magnitude >> shift;
+    let half = magnitude.bit(shift - 1);
+    let rest = sticky
+        || magnitude
+            .trailing_zeros()
+            .is_some_and(|zeros| zeros < shift - 1);
+    let discarded = match (half, rest) {
+        (false, false) => None,
+        (false, true) => Some(Ordering::Less),
+        (true, false) => Some(Ordering::Equal),
+        (true, true) => Some(Ordering::Greater),
+    };
+
+    if mode.round_up(negative, !quotient.is_even(), discarded) {
+        &quotient + 1u8
+    } else {
+        quotient
+    }
+}
@@ -0,0 +1,133 @@
+// Integer sequences
+
+use crate::bignat::sieve::sieve;
+use crate::{APNum, BigInt, BigNat, BigRational};
+
+/// nth Fibonacci number, F(0) = 0, F(1) = 1
+pub fn fibonacci(n: u64) -> BigNat {
+    fibonacci_pair(n).0
+}
+
+/// nth Lucas number, L(0) = 2, L(1) = 1
+pub fn lucas(n: u64) -> BigNat {
+    // L(n) = 2F(n + 1) - F(n)
+    let (f, g) = fibonacci_pair(n);
+    (&(&g << 1) - &f).natural
+}
+
+/// (F(n), F(n + 1)) by fast doubling,
+/// F(2k) = F(k) * (2F(k + 1) - F(k)), F(2k + 1) = F(k)^2 + F(k + 1)^2
+fn fibonacci_pair(n: u64) -> (BigNat, BigNat) {
+    let (mut f, mut g) = (BigNat::zero(), BigNat::from(1usize));
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        let double = &f * &(&(&g << 1) - &f).natural;
+        let double_plus_one = &(&f * &f) + &(&g * &g);
+
+        (f, g) = if n >> bit & 1 == 1 {
+            let next = &double + &double_plus_one;
+            (double_plus_one, next)
+        } else {
+            (double, double_plus_one)
+        };
+    }
+    (f, g)
+}
+
+/// nth Catalan number, C(n) = (2n choose n) / (n + 1)
+pub fn catalan(n: u32) -> BigNat {
+    let (catalan, remainder) = BigNat::binomial(2 * n, n) / (n + 1);
+    debug_assert!(remainder == 0);
+    catalan
+}
+
+/// Product of the primes up to n
+pub fn primorial(n: u32) -> BigNat {
+    BigNat::product(sieve(n).into_iter().map(BigNat::from))
+}
+
+/// Stirling number of the second kind, the number of partitions of n elements into k nonempty sets,
+/// S(n, k) = 1/k! * Σ (-1)^(k - j) * (k choose j) * j^n for j in [0; k]
+pub fn stirling2(n: u32, k: u32) -> BigNat {
+    if k > n {
+        return BigNat::zero();
+    }
+
+    let mut sum = BigInt::zero();
+    for j in 0..=k {
+        let term = BigInt::from(BigNat::binomial(k, j) * BigNat::from(j).pow(n));
+        sum = if (k - j).is_multiple_of(2) {
+            sum + term
+        } else {
+            sum - term
+        };
+    }
+
+    let (stirling, remainder) = BigNat::try_from(sum).unwrap() / BigNat::factorial(k);
+    debug_assert!(remainder.is_zero());
+    stirling
+}
+
+/// Number of partitions of n, by Euler's pentagonal number theorem,
+/// p(n) = Σ (-1)^(k + 1) * (p(n - k(3k - 1)/2) + p(n - k(3k + 1)/2)) for k ≥ 1
+pub fn partition_count(n: usize) -> BigNat {
+    let mut partitions = vec![BigNat::from(1usize)];
+    for m in 1..=n {
+        // Sums of the positive and the negative terms
+        let (mut positive, mut negative) = (BigNat::zero(), BigNat::zero());
+        for k in 1.. {
+            let pentagonal = k * (3 * k - 1) / 2;
+            if pentagonal > m {
+                break;
+            }
+
+            let sum = if k % 2 == 1 {
+                &mut positive
+            } else {
+                &mut negative
+            };
+            *sum = &*sum + &partitions[m - pentagonal];
+            if pentagonal + k <= m {
+                *sum = &*sum + &partitions[m - pentagonal - k];
+            }
+        }
+
+        partitions.push((&positive - &negative).natural);
+    }
+
+    partitions.pop().unwrap()
+}
+
+/// nth Bernoulli number, with B(1) = -1/2
+pub fn bernoulli(n: u32) -> BigRational {
+    match n {
+        0 => return BigRational::from(1),
+        1 => return BigRational::new(BigInt::from(-1), BigNat::from(2usize)),
+        _ if n % 2 == 1 => return BigRational::zero(),
+        _ => (),
+    }
+
+    // B(2k) = (-1)^(k - 1) * 2k * T(k) / (2^2k * (2^2k - 1)) with the tangent numbers T(k)
+    // see. Brent and Harvey, Fast computation of Bernoulli, Tangent and Secant numbers, Algorithm 2
+    let k = (n / 2) as usize;
+    let mut tangent = vec![BigNat::zero(); k + 1];
+    tangent[1] = BigNat::from(1usize);
+    for i in 2..=k {
+        tangent[i] = &tangent[i - 1] * (i as u32 - 1);
+    }
+    for i in 2..=k {
+        for j in i..=k {
+            tangent[j] = &(&tangent[j - 1] * (j - i) as u32) + &(&tangent[j] * (j - i + 2) as u32);
+        }
+    }
+
+    let numerator = &tangent[k] * n;
+    let power = BigNat::from(1usize) << n as usize;
+    let denominator = &power * &(&power - 1u8).natural;
+
+    let numerator = BigInt::from(numerator);
+    if k % 2 == 1 {
+        BigRational::new(numerator, denominator)
+    } else {
+        BigRational::new(-numerator, denominator)
+    }
+}
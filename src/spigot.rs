@@ -0,0 +1,99 @@
+// Decimal digits of constants streamed one at a time
+
+use crate::{APNum, BigInt};
+
+/// Decimal digits of a constant one at a time, starting with the integer part, by an unbounded
+/// spigot: the constant is the composition of infinitely many linear fractional transformations
+/// applied to a tail within known bounds, and a digit is produced as soon as both bounds agree on
+/// it.
+// see. Gibbons, Unbounded spigot algorithms for the digits of pi (2006)
+pub struct SpigotDigits {
+    constant: Constant,
+    /// The transformations consumed so far, x -> (q * x + r) / t
+    q: BigInt,
+    r: BigInt,
+    t: BigInt,
+    /// Index of the next transformation
+    k: u64,
+}
+
+enum Constant {
+    Pi,
+    E,
+}
+
+/// Digits of π, 3, 1, 4, 1, 5, ...
+pub fn pi_digits() -> SpigotDigits {
+    SpigotDigits::new(Constant::Pi)
+}
+
+/// Digits of e, 2, 7, 1, 8, 2, ...
+pub fn e_digits() -> SpigotDigits {
+    SpigotDigits::new(Constant::E)
+}
+
+impl SpigotDigits {
+    fn new(constant: Constant) -> Self {
+        SpigotDigits {
+            constant,
+            q: BigInt::from(1),
+            r: BigInt::zero(),
+            t: BigInt::from(1),
+            k: 1,
+        }
+    }
+
+    /// Bounds of the value of the transformations not consumed yet
+    fn tail_bounds(&self) -> (u32, u32) {
+        match self.constant {
+            Constant::Pi => (3, 4),
+            Constant::E => (1, 2),
+        }
+    }
+
+    /// Composes the next transformation
+    fn consume(&mut self) {
+        let k = BigInt::from(self.k);
+        match self.constant {
+            // π = 2 + 1/3 (2 + 2/5 (2 + 3/7 (2 + ...))), x -> (k x + 4k + 2) / (2k + 1)
+            Constant::Pi => {
+                let odd = BigInt::from(2 * self.k + 1);
+                self.r = &(&(&self.q * 2) + &self.r) * &odd;
+                self.q = &self.q * &k;
+                self.t = &self.t * &odd;
+            }
+            // e = 1 + 1/1 (1 + 1/2 (1 + 1/3 (1 + ...))), x -> (x + k) / k
+            Constant::E => {
+                self.r = &(&self.q + &self.r) * &k;
+                self.t = &self.t * &k;
+            }
+        }
+        self.k += 1;
+    }
+
+    /// floor((q * x + r) / t)
+    fn floor_at(&self, x: u32) -> BigInt {
+        (&(&(&self.q * x) + &self.r) / &self.t).0
+    }
+}
+
+impl Iterator for SpigotDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (low, high) = self.tail_bounds();
+        loop {
+            let digit = self.floor_at(low);
+            if digit != self.floor_at(high) {
+                self.consume();
+                continue;
+            }
+
+            // x -> 10 ((q * x + r) / t - digit)
+            self.r = &(&self.r - &(&digit * &self.t)) * 10;
+            self.q = &self.q * 10;
+            let digit: u32 = (&digit.natural).try_into().unwrap();
+            return Some(digit as u8);
+        }
+    }
+}
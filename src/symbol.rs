@@ -0,0 +1,86 @@
+// Jacobi, Legendre and Kronecker symbols
+
+use crate::{APNum, BigInt, BigNat, Sign};
+
+/// Jacobi symbol (a/n) for odd n
+// see. Cohen, A Course in Computational Algebraic Number Theory, Algorithm 1.4.10
+pub fn jacobi(a: &BigInt, n: &BigNat) -> i8 {
+    if n.is_even() {
+        panic!("Jacobi symbol with an even modulus");
+    }
+
+    // (-a/n) = (-1/n)(a/n), and (-1/n) = -1 for n ≡ 3 (mod 4)
+    let sign = match a.sign {
+        Sign::Negative if n.digits[0] % 4 == 3 => -1,
+        _ => 1,
+    };
+
+    sign * jacobi_natural(&a.natural, n)
+}
+
+/// Legendre symbol (a/p) for odd prime p (which isn't checked)
+pub fn legendre(a: &BigInt, p: &BigNat) -> i8 {
+    jacobi(a, p)
+}
+
+/// Kronecker symbol (a/n), extension of the Jacobi symbol to every n
+// see. Cohen, A Course in Computational Algebraic Number Theory, Algorithm 1.4.10
+pub fn kronecker(a: &BigInt, n: &BigInt) -> i8 {
+    // (a/0) = 1 for a = ±1, 0 otherwise
+    if n.is_zero() {
+        return if a.natural.eq_u8(1) { 1 } else { 0 };
+    }
+
+    // (a/-1) = -1 for negative a
+    let mut result = match (&a.sign, &n.sign) {
+        (Sign::Negative, Sign::Negative) => -1,
+        _ => 1,
+    };
+
+    // n = 2^v * m, m odd
+    let v = n.natural.trailing_zeros().unwrap();
+    if v > 0 {
+        if a.natural.is_even() {
+            return 0;
+        }
+
+        // (a/2) = -1 for a ≡ 3, 5 (mod 8)
+        let a_residue = match a.sign {
+            Sign::Negative => 8 - a.natural.digits[0] % 8,
+            _ => a.natural.digits[0] % 8,
+        };
+        if v % 2 == 1 && matches!(a_residue, 3 | 5) {
+            result = -result;
+        }
+    }
+
+    result * jacobi(a, &(&n.natural >> v))
+}
+
+fn jacobi_natural(a: &BigNat, n: &BigNat) -> i8 {
+    let (_, mut a) = a / n;
+    let mut n = n.clone();
+    let mut result = 1;
+
+    while !a.is_zero() {
+        // Remove the factors of two, (2/n) = -1 for n ≡ 3, 5 (mod 8)
+        let zeros = a.trailing_zeros().unwrap();
+        a = a >> zeros;
+        if zeros % 2 == 1 && matches!(n.digits[0] % 8, 3 | 5) {
+            result = -result;
+        }
+
+        // Quadratic reciprocity, (a/n) = -(n/a) for a ≡ n ≡ 3 (mod 4)
+        if a.digits[0] % 4 == 3 && n.digits[0] % 4 == 3 {
+            result = -result;
+        }
+
+        (a, n) = ((&n / &a).1, a);
+    }
+
+    if n.eq_u8(1) {
+        result
+    } else {
+        0
+    }
+}
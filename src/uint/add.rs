@@ -0,0 +1,48 @@
+use crate::{BigDigit, BiggerDigit, Uint, BASE};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// (self + rhs modulo 2^BITS, whether it overflowed)
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut carry = 0;
+        for (position, limb) in limbs.iter_mut().enumerate() {
+            // digit_sum ϵ [0; (2^32 - 1) + (2^32 - 1) + 1] ⊂ u64
+            let digit_sum = self.limbs[position] as BiggerDigit
+                + rhs.limbs[position] as BiggerDigit
+                + carry as BiggerDigit;
+            carry = (digit_sum / BASE) as BigDigit;
+            *limb = (digit_sum % BASE) as BigDigit;
+        }
+        (Uint { limbs }, carry > 0)
+    }
+
+    /// self + rhs modulo 2^BITS
+    pub fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// self + rhs, None when it overflows
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (sum, false) => Some(sum),
+            (_, true) => None,
+        }
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Add for &Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Wraps around on overflow
+    fn add(self, rhs: Self) -> Self::Output {
+        self.wrapping_add(rhs)
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Add for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        (&self).add(&rhs)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::Uint;
+
+macro_rules! impl_bitwise {
+    ($($op:ident $method:ident $assign:tt;)*) => ($(
+        impl<const LIMBS: usize> std::ops::$op for &Uint<LIMBS> {
+            type Output = Uint<LIMBS>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                let mut limbs = self.limbs;
+                for (limb, right_limb) in limbs.iter_mut().zip(&rhs.limbs) {
+                    *limb $assign right_limb;
+                }
+                Uint { limbs }
+            }
+        }
+
+        impl<const LIMBS: usize> std::ops::$op for Uint<LIMBS> {
+            type Output = Uint<LIMBS>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+    )*)
+}
+
+impl_bitwise! {
+    BitAnd bitand &=;
+    BitOr bitor |=;
+    BitXor bitxor ^=;
+}
+
+impl<const LIMBS: usize> std::ops::Not for &Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    fn not(self) -> Self::Output {
+        Uint {
+            limbs: self.limbs.map(|limb| !limb),
+        }
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Not for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    fn not(self) -> Self::Output {
+        !(&self)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::Uint;
+
+impl<const LIMBS: usize> std::cmp::Ord for Uint<LIMBS> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // From the most significant digit
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+impl<const LIMBS: usize> std::cmp::PartialOrd for Uint<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{APNum, Uint};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// (self / rhs, self % rhs), None when rhs is zero
+    pub fn checked_div(&self, rhs: &Self) -> Option<(Self, Self)> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        // Binary long division, r < rhs before each step so r * 2 + 1 ≥ 2^BITS only when it is
+        // above rhs, and the wrapping subtraction is then exact
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+        for index in (0..self.bits()).rev() {
+            let overflow = remainder.bit(Self::BITS - 1);
+            remainder = &remainder << 1;
+            if self.bit(index) {
+                remainder.set_bit(0);
+            }
+            if overflow || remainder >= *rhs {
+                remainder = remainder.wrapping_sub(rhs);
+                quotient.set_bit(index);
+            }
+        }
+        Some((quotient, remainder))
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Div for &Uint<LIMBS> {
+    type Output = (Uint<LIMBS>, Uint<LIMBS>);
+
+    fn div(self, rhs: Self) -> Self::Output {
+        match self.checked_div(rhs) {
+            Some(result) => result,
+            None => panic!("Division by Zero"),
+        }
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Div for Uint<LIMBS> {
+    type Output = (Uint<LIMBS>, Uint<LIMBS>);
+
+    fn div(self, rhs: Self) -> Self::Output {
+        (&self).div(&rhs)
+    }
+}
@@ -0,0 +1,169 @@
+pub mod add;
+pub mod bitwise;
+pub mod cmp;
+pub mod div;
+pub mod mul;
+pub mod shift;
+pub mod sub;
+
+use crate::{APNum, APNumParseError, BigDigit, BigNat, Uint};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    pub const BITS: usize = LIMBS * BigDigit::BITS as usize;
+    pub const ZERO: Self = Uint { limbs: [0; LIMBS] };
+    pub const MAX: Self = Uint {
+        limbs: [BigDigit::MAX; LIMBS],
+    };
+
+    /// The number with the given digits, in reverse order
+    pub const fn from_limbs(limbs: [BigDigit; LIMBS]) -> Self {
+        Uint { limbs }
+    }
+
+    /// Digits in reverse order
+    pub fn limbs(&self) -> &[BigDigit; LIMBS] {
+        &self.limbs
+    }
+
+    /// Number of bits needed to represent the number (0 for zero)
+    pub fn bits(&self) -> usize {
+        Self::BITS - self.leading_zeros()
+    }
+
+    pub fn leading_zeros(&self) -> usize {
+        match self.limbs.iter().rposition(|limb| *limb != 0) {
+            Some(position) => {
+                (LIMBS - 1 - position) * BigDigit::BITS as usize
+                    + self.limbs[position].leading_zeros() as usize
+            }
+            None => Self::BITS,
+        }
+    }
+
+    /// Whether the bit at the given index (from the least significant one) is set
+    pub fn bit(&self, index: usize) -> bool {
+        let limb = self
+            .limbs
+            .get(index / BigDigit::BITS as usize)
+            .unwrap_or(&0);
+        limb >> (index % BigDigit::BITS as usize) & 1 == 1
+    }
+
+    pub(crate) fn set_bit(&mut self, index: usize) {
+        self.limbs[index / BigDigit::BITS as usize] |= 1 << (index % BigDigit::BITS as usize);
+    }
+}
+
+impl<const LIMBS: usize> APNum for Uint<LIMBS> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|limb| *limb == 0)
+    }
+
+    fn zero_normalized(self) -> Self {
+        self
+    }
+
+    /// Number of significant digits
+    fn digit_count(&self) -> usize {
+        self.limbs
+            .iter()
+            .rposition(|limb| *limb != 0)
+            .map_or(0, |last| last + 1)
+    }
+}
+
+impl<const LIMBS: usize> Default for Uint<LIMBS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize> From<u32> for Uint<LIMBS> {
+    /// The value modulo 2^BITS
+    fn from(value: u32) -> Self {
+        Uint::from(value as u64)
+    }
+}
+
+impl<const LIMBS: usize> From<u64> for Uint<LIMBS> {
+    /// The value modulo 2^BITS
+    fn from(value: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        for (position, limb) in limbs.iter_mut().take(2).enumerate() {
+            *limb = (value >> (position * BigDigit::BITS as usize)) as BigDigit;
+        }
+        Uint { limbs }
+    }
+}
+
+impl<const LIMBS: usize> From<&Uint<LIMBS>> for BigNat {
+    fn from(value: &Uint<LIMBS>) -> Self {
+        BigNat {
+            digits: value.limbs.to_vec(),
+        }
+        .zero_normalized()
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for BigNat {
+    fn from(value: Uint<LIMBS>) -> Self {
+        BigNat::from(&value)
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&BigNat> for Uint<LIMBS> {
+    type Error = ();
+
+    /// Fails for values of more than BITS bits
+    fn try_from(value: &BigNat) -> Result<Self, Self::Error> {
+        if value.digits.len() > LIMBS {
+            return Err(());
+        }
+
+        let mut limbs = [0; LIMBS];
+        limbs[..value.digits.len()].copy_from_slice(&value.digits);
+        Ok(Uint { limbs })
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<BigNat> for Uint<LIMBS> {
+    type Error = ();
+
+    fn try_from(value: BigNat) -> Result<Self, Self::Error> {
+        Uint::try_from(&value)
+    }
+}
+
+impl<const LIMBS: usize> std::str::FromStr for Uint<LIMBS> {
+    type Err = APNumParseError;
+
+    /// Decimal digits, the value has at most BITS bits
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let natural = s.parse::<BigNat>()?;
+        Uint::try_from(&natural).map_err(|_| APNumParseError::Invalid)
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&str> for Uint<LIMBS> {
+    type Error = APNumParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl<const LIMBS: usize> std::fmt::Display for Uint<LIMBS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&BigNat::from(self), f)
+    }
+}
+
+impl<const LIMBS: usize> std::fmt::Debug for Uint<LIMBS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self, f)
+    }
+}
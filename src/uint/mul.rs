@@ -0,0 +1,70 @@
+use crate::{BigDigit, BiggerDigit, Uint, BASE};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// (self * rhs modulo 2^BITS, whether it overflowed)
+    pub fn overflowing_mul(&self, rhs: &Self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut overflow = false;
+        for (i, left_digit) in self.limbs.iter().enumerate() {
+            if *left_digit == 0 {
+                continue;
+            }
+
+            // Only the products below BASE^LIMBS are kept, the others overflow when nonzero
+            let mut carry = 0;
+            for (j, right_digit) in rhs.limbs[..LIMBS - i].iter().enumerate() {
+                // digit_product ϵ [0; (2^32 - 1)^2 + 2 (2^32 - 1)] = [0; 2^64 - 1]
+                let digit_product = *left_digit as BiggerDigit * *right_digit as BiggerDigit
+                    + limbs[i + j] as BiggerDigit
+                    + carry as BiggerDigit;
+                carry = (digit_product / BASE) as BigDigit;
+                limbs[i + j] = (digit_product % BASE) as BigDigit;
+            }
+            overflow |= carry > 0 || rhs.limbs[LIMBS - i..].iter().any(|digit| *digit != 0);
+        }
+        (Uint { limbs }, overflow)
+    }
+
+    /// self * rhs modulo 2^BITS
+    pub fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// self * rhs, None when it overflows
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (product, false) => Some(product),
+            (_, true) => None,
+        }
+    }
+
+    /// self^power modulo 2^BITS
+    pub fn wrapping_pow(&self, power: u32) -> Self {
+        // Square-and-multiply, scanning the bits of power from the most significant one
+        let mut acc = Uint::from(1u32);
+        for bit in (0..u32::BITS - power.leading_zeros()).rev() {
+            acc = acc.wrapping_mul(&acc);
+            if power >> bit & 1 == 1 {
+                acc = acc.wrapping_mul(self);
+            }
+        }
+        acc
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Mul for &Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Wraps around on overflow
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.wrapping_mul(rhs)
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Mul for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        (&self).mul(&rhs)
+    }
+}
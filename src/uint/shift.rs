@@ -0,0 +1,52 @@
+use crate::{BigDigit, Uint};
+
+impl<const LIMBS: usize> std::ops::Shl<usize> for &Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// The bits shifted past BITS are lost
+    fn shl(self, rhs: usize) -> Self::Output {
+        let digit_shift = rhs / BigDigit::BITS as usize;
+        let bit_shift = rhs % BigDigit::BITS as usize;
+        let mut limbs = [0; LIMBS];
+        for (source, limb) in limbs.iter_mut().skip(digit_shift).enumerate() {
+            *limb = self.limbs[source] << bit_shift;
+            if bit_shift > 0 && source > 0 {
+                *limb |= self.limbs[source - 1] >> (BigDigit::BITS as usize - bit_shift);
+            }
+        }
+        Uint { limbs }
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Shl<usize> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    fn shl(self, rhs: usize) -> Self::Output {
+        (&self).shl(rhs)
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Shr<usize> for &Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        let digit_shift = rhs / BigDigit::BITS as usize;
+        let bit_shift = rhs % BigDigit::BITS as usize;
+        let mut limbs = [0; LIMBS];
+        for (limb, source) in limbs.iter_mut().zip(digit_shift..LIMBS) {
+            *limb = self.limbs[source] >> bit_shift;
+            if bit_shift > 0 && source + 1 < LIMBS {
+                *limb |= self.limbs[source + 1] << (BigDigit::BITS as usize - bit_shift);
+            }
+        }
+        Uint { limbs }
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Shr<usize> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        (&self).shr(rhs)
+    }
+}
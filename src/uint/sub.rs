@@ -0,0 +1,53 @@
+use crate::{APNum, BigDigit, BiggerDigit, Uint, BASE};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// (self - rhs modulo 2^BITS, whether it overflowed)
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let mut borrow = 0;
+        for (position, limb) in limbs.iter_mut().enumerate() {
+            // digit_difference ϵ [0; 2^32 + (2^32 - 1)] ⊂ u64
+            let digit_difference = BASE + self.limbs[position] as BiggerDigit
+                - rhs.limbs[position] as BiggerDigit
+                - borrow as BiggerDigit;
+            borrow = 1 - (digit_difference / BASE) as BigDigit;
+            *limb = (digit_difference % BASE) as BigDigit;
+        }
+        (Uint { limbs }, borrow > 0)
+    }
+
+    /// self - rhs modulo 2^BITS
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// self - rhs, None when rhs is larger
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (difference, false) => Some(difference),
+            (_, true) => None,
+        }
+    }
+
+    /// -self modulo 2^BITS
+    pub fn wrapping_neg(&self) -> Self {
+        Self::zero().wrapping_sub(self)
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Sub for &Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Wraps around on overflow
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl<const LIMBS: usize> std::ops::Sub for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        (&self).sub(&rhs)
+    }
+}
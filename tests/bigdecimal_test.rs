@@ -0,0 +1,161 @@
+use apnum::{APNum, BigDecimal, BigInt, RoundingMode};
+
+fn decimal(s: &str) -> BigDecimal {
+    BigDecimal::try_from(s).unwrap()
+}
+
+#[test]
+fn bigdecimal_add() {
+    let x = decimal("1.25") + decimal("0.755");
+    assert_eq!(x.unscaled(), &BigInt::from(2005));
+    assert_eq!(x.scale(), 3);
+    assert_eq!(decimal("-1.5") + decimal("1.50"), BigDecimal::zero());
+    assert_eq!(decimal("0.1") + decimal("0.2"), decimal("0.3"));
+    assert_eq!(decimal("1e3") + decimal("1"), decimal("1001"));
+}
+
+#[test]
+fn bigdecimal_sub() {
+    let x = decimal("1.25") - decimal("0.755");
+    assert_eq!(x.to_string(), "0.495");
+    assert_eq!(decimal("0.3") - decimal("0.1"), decimal("0.2"));
+    assert_eq!(decimal("-1") - decimal("1.005"), decimal("-2.005"));
+}
+
+#[test]
+fn bigdecimal_mul() {
+    let x = decimal("1.20") * decimal("-0.05");
+    assert_eq!(x.to_string(), "-0.0600");
+    assert_eq!(x.scale(), 4);
+    assert_eq!(decimal("12e3") * decimal("0.5"), decimal("6000"));
+}
+
+#[test]
+fn bigdecimal_div() {
+    let cases = [
+        // x, y, scale, half even, half up, down, ceiling, floor
+        ("1", "8", 2, "0.12", "0.13", "0.12", "0.13", "0.12"),
+        ("-1", "8", 2, "-0.12", "-0.13", "-0.12", "-0.12", "-0.13"),
+        ("3", "8", 2, "0.38", "0.38", "0.37", "0.38", "0.37"),
+        (
+            "2", "3", 4, "0.6667", "0.6667", "0.6666", "0.6667", "0.6666",
+        ),
+        ("-2", "3", 0, "-1", "-1", "0", "0", "-1"),
+        ("100", "0.04", 0, "2500", "2500", "2500", "2500", "2500"),
+    ];
+    let modes = [
+        RoundingMode::HalfEven,
+        RoundingMode::HalfUp,
+        RoundingMode::Down,
+        RoundingMode::Ceiling,
+        RoundingMode::Floor,
+    ];
+    for (x, y, scale, half_even, half_up, down, ceiling, floor) in cases {
+        let expected = [half_even, half_up, down, ceiling, floor];
+        for (mode, expected) in modes.into_iter().zip(expected) {
+            let quotient = decimal(x).div_rounded(&decimal(y), scale, mode);
+            assert_eq!(quotient, decimal(expected));
+            assert_eq!(quotient.scale(), scale);
+        }
+    }
+
+    let x = decimal("1").div_rounded(&decimal("3"), -1, RoundingMode::Ceiling);
+    assert_eq!(x.to_string(), "1e1");
+}
+
+#[test]
+#[should_panic(expected = "Division by Zero")]
+fn bigdecimal_div_by_zero() {
+    let _ = decimal("1").div_rounded(&BigDecimal::zero(), 2, RoundingMode::HalfEven);
+}
+
+#[test]
+fn bigdecimal_rescale() {
+    let x = decimal("2.345");
+    assert_eq!(x.rescale(2, RoundingMode::HalfEven).to_string(), "2.34");
+    assert_eq!(x.rescale(2, RoundingMode::HalfUp).to_string(), "2.35");
+    assert_eq!(x.rescale(5, RoundingMode::Down).to_string(), "2.34500");
+    assert_eq!(x.rescale(-1, RoundingMode::Ceiling).to_string(), "1e1");
+    assert_eq!((-x).rescale(0, RoundingMode::Floor).to_string(), "-3");
+    assert_eq!(
+        decimal("0.001").rescale(2, RoundingMode::Down).to_string(),
+        "0.00"
+    );
+    let x = BigDecimal::new(BigInt::from(0), i64::MIN).rescale(i64::MAX, RoundingMode::Down);
+    assert_eq!(x.scale(), i64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Scale out of range")]
+fn bigdecimal_rescale_out_of_range() {
+    decimal("1").rescale(1 << 32, RoundingMode::Down);
+}
+
+#[test]
+#[should_panic(expected = "Scale out of range")]
+fn bigdecimal_div_scale_out_of_range() {
+    let x = BigDecimal::new(BigInt::from(1), i64::MIN);
+    x.div_rounded(&decimal("1"), i64::MAX, RoundingMode::Down);
+}
+
+#[test]
+fn bigdecimal_normalize() {
+    let x = decimal("-123.4500e-7").normalize();
+    assert_eq!(x.unscaled(), &BigInt::from(-12345));
+    assert_eq!(x.scale(), 9);
+    let x = decimal("1200").normalize();
+    assert_eq!(x.unscaled(), &BigInt::from(12));
+    assert_eq!(x.scale(), -2);
+    let x = decimal("0.000").normalize();
+    assert_eq!(x.scale(), 0);
+}
+
+#[test]
+fn bigdecimal_cmp() {
+    assert_eq!(decimal("1.0"), decimal("1.000"));
+    assert!(decimal("1.01") > decimal("1.001"));
+    assert!(decimal("-1.01") < decimal("-1.001"));
+    assert!(decimal("1e-10") > BigDecimal::zero());
+    assert!(decimal("-1e10") < decimal("-9999999999"));
+
+    // Scales far apart are ordered by the magnitudes without aligning
+    let one = decimal("1");
+    let tiny = BigDecimal::new(BigInt::from(1), 1 << 33);
+    assert_ne!(one, tiny);
+    assert!(one > tiny && -&tiny > -&one);
+    assert!(
+        BigDecimal::new(BigInt::from(1), i64::MIN) > BigDecimal::new(BigInt::from(1), i64::MAX)
+    );
+    assert_eq!(
+        BigDecimal::new(BigInt::from(0), i64::MIN),
+        BigDecimal::new(BigInt::from(0), i64::MAX)
+    );
+    assert_eq!(decimal("1.2"), decimal("1.200"));
+    assert!(decimal("-1.2") < decimal("-1.19999"));
+}
+
+#[test]
+fn bigdecimal_display() {
+    let cases = [
+        ("-123.4500e-7", "-0.00001234500"),
+        ("123.4500", "123.4500"),
+        ("0.000", "0.000"),
+        ("1.5e10", "1.5e10"),
+        ("0", "0"),
+        ("1e-7", "1e-7"),
+        ("12.5e-8", "1.25e-7"),
+        ("-0.0000001", "-1e-7"),
+        ("0.000001", "0.000001"),
+    ];
+    for (input, output) in cases {
+        let x = decimal(input);
+        assert_eq!(x.to_string(), output);
+        // Lossless round trip
+        let y = decimal(output);
+        assert_eq!((x.unscaled(), x.scale()), (y.unscaled(), y.scale()));
+    }
+
+    assert!(BigDecimal::try_from("").is_err());
+    assert!(BigDecimal::try_from("1.2.3").is_err());
+    assert!(BigDecimal::try_from("1e").is_err());
+}
@@ -0,0 +1,436 @@
+use apnum::{APNum, BigFloat, BigInt, RoundingMode};
+
+fn float(mantissa: i64, exponent: i64) -> BigFloat {
+    BigFloat::new(BigInt::from(mantissa), exponent, 64, RoundingMode::HalfEven)
+}
+
+#[test]
+fn bigfloat_new() {
+    let x = float(12, 3);
+    assert_eq!(x.mantissa(), &BigInt::from(3));
+    assert_eq!(x.exponent(), 5);
+    assert_eq!(x.precision(), 64);
+
+    // 5/2 and 7/2 are ties at two bits
+    let x = BigFloat::new(BigInt::from(5), -1, 2, RoundingMode::HalfEven);
+    assert_eq!(x, float(2, 0));
+    let x = BigFloat::new(BigInt::from(7), -1, 2, RoundingMode::HalfEven);
+    assert_eq!(x, float(4, 0));
+    let x = BigFloat::new(BigInt::from(5), -1, 2, RoundingMode::HalfUp);
+    assert_eq!(x, float(3, 0));
+    let x = BigFloat::new(BigInt::from(-5), -1, 2, RoundingMode::HalfUp);
+    assert_eq!(x, float(-3, 0));
+}
+
+#[test]
+fn bigfloat_add() {
+    assert_eq!(float(3, -1) + float(5, -2), float(11, -2));
+    assert_eq!(float(3, -1) + float(-3, -1), BigFloat::zero());
+
+    // 1 + 2^-1000 only rounds away from 1 toward positive infinity
+    let one = float(1, 0);
+    let tiny = float(1, -1000);
+    let cases = [
+        (RoundingMode::HalfEven, float(1, 0)),
+        (RoundingMode::HalfUp, float(1, 0)),
+        (RoundingMode::Down, float(1, 0)),
+        (RoundingMode::Floor, float(1, 0)),
+        (RoundingMode::Ceiling, float((1 << 52) + 1, -52)),
+    ];
+    for (mode, expected) in cases {
+        assert_eq!(one.add_rounded(&tiny, 53, mode), expected);
+    }
+    assert_eq!(
+        one.sub_rounded(&tiny, 53, RoundingMode::Down),
+        float((1 << 53) - 1, -53)
+    );
+    assert_eq!(one.sub_rounded(&tiny, 53, RoundingMode::HalfEven), one);
+}
+
+#[test]
+fn bigfloat_sub() {
+    assert_eq!(float(3, -1) - float(5, -2), float(1, -2));
+    assert_eq!(float(5, -2) - float(3, -1), float(-1, -2));
+    assert_eq!(float(5, 10) - float(5, 10), BigFloat::zero());
+}
+
+#[test]
+fn bigfloat_mul() {
+    assert_eq!(float(3, -1) * float(-5, 2), float(-15, 1));
+    assert_eq!(float(3, -1) * BigFloat::zero(), BigFloat::zero());
+    // 7 * 7 = 49 = 110001b, rounded to 3 bits
+    let x = float(7, 0);
+    assert_eq!(x.mul_rounded(&x, 3, RoundingMode::HalfEven), float(3, 4));
+    assert_eq!(x.mul_rounded(&x, 3, RoundingMode::Down), float(6, 3));
+    assert_eq!(x.mul_rounded(&x, 3, RoundingMode::Ceiling), float(7, 3));
+}
+
+#[test]
+fn bigfloat_div() {
+    // 1/3 rounded to 10 bits
+    let one = float(1, 0);
+    let three = float(3, 0);
+    let cases = [
+        (RoundingMode::HalfEven, (683, -11), (-683, -11)),
+        (RoundingMode::HalfUp, (683, -11), (-683, -11)),
+        (RoundingMode::Down, (341, -10), (-341, -10)),
+        (RoundingMode::Ceiling, (683, -11), (-341, -10)),
+        (RoundingMode::Floor, (341, -10), (-683, -11)),
+    ];
+    for (mode, positive, negative) in cases {
+        assert_eq!(
+            one.div_rounded(&three, 10, mode),
+            float(positive.0, positive.1)
+        );
+        assert_eq!(
+            (-&one).div_rounded(&three, 10, mode),
+            float(negative.0, negative.1)
+        );
+    }
+    assert_eq!(float(3, 0) / float(4, 0), float(3, -2));
+}
+
+#[test]
+#[should_panic(expected = "Division by Zero")]
+fn bigfloat_div_by_zero() {
+    let _ = float(1, 0) / BigFloat::zero();
+}
+
+#[test]
+fn bigfloat_sqrt() {
+    let two = BigFloat::new(BigInt::from(2), 0, 100, RoundingMode::HalfEven);
+    let root = two.sqrt();
+    assert_eq!(
+        root.mantissa(),
+        &BigInt::try_from("896364335596578238699711011639").unwrap()
+    );
+    assert_eq!(root.exponent(), -99);
+    assert_eq!(root.to_string(), "1.4142135623730950488016887242092");
+
+    assert_eq!(float(9, -4).sqrt(), float(3, -2));
+    assert_eq!(BigFloat::zero().sqrt(), BigFloat::zero());
+    assert!(
+        two.sqrt_rounded(20, RoundingMode::Floor) < two.sqrt_rounded(20, RoundingMode::Ceiling)
+    );
+}
+
+#[test]
+fn bigfloat_cmp() {
+    assert!(float(1, 0) > float(-1, 10));
+    assert!(float(-1, 0) > float(-1, 10));
+    assert!(float(3, 0) > float(5, -1));
+    assert!(float(-3, 0) < float(-5, -1));
+    assert!(float(1, -2000) > BigFloat::zero());
+    // Equality doesn't depend on the precision
+    let x = BigFloat::new(BigInt::from(3), -1, 2, RoundingMode::HalfEven);
+    assert_eq!(x, float(3, -1));
+}
+
+#[test]
+fn bigfloat_f64() {
+    let x = BigFloat::try_from(0.1).unwrap();
+    assert_eq!(x, float(3602879701896397, -55));
+    assert_eq!(x.to_f64(RoundingMode::HalfEven), 0.1);
+    assert_eq!(x.to_string(), "0.10000000000000001");
+
+    for value in [1.0, -2.5, f64::MAX, f64::MIN_POSITIVE, 5e-324, -1e-310] {
+        let x = BigFloat::try_from(value).unwrap();
+        assert_eq!(x.to_f64(RoundingMode::HalfEven), value);
+    }
+    assert!(BigFloat::try_from(f64::NAN).is_err());
+    assert!(BigFloat::try_from(f64::INFINITY).is_err());
+
+    // 1/3 to f64 in every direction
+    let third = float(1, 0).div_rounded(&float(3, 0), 200, RoundingMode::HalfEven);
+    assert_eq!(third.to_f64(RoundingMode::HalfEven), 1.0 / 3.0);
+    assert_eq!(third.to_f64(RoundingMode::Floor), 1.0 / 3.0);
+    assert_eq!(
+        third.to_f64(RoundingMode::Ceiling),
+        f64::from_bits((1.0f64 / 3.0).to_bits() + 1)
+    );
+
+    // Out of range
+    assert_eq!(float(1, 1024).to_f64(RoundingMode::HalfEven), f64::INFINITY);
+    assert_eq!(float(1, 1024).to_f64(RoundingMode::Down), f64::MAX);
+    assert_eq!(float(-1, 1024).to_f64(RoundingMode::Ceiling), -f64::MAX);
+    assert_eq!(float(1, -1076).to_f64(RoundingMode::HalfEven), 0.0);
+    assert_eq!(float(1, -1076).to_f64(RoundingMode::Ceiling), 5e-324);
+    assert_eq!(float(3, -1076).to_f64(RoundingMode::HalfEven), 5e-324);
+}
+
+#[test]
+fn bigfloat_parse() {
+    assert_eq!(
+        BigFloat::try_from("0.1").unwrap(),
+        BigFloat::try_from(0.1).unwrap()
+    );
+    assert_eq!(
+        BigFloat::try_from("-1.25e-3").unwrap().to_string(),
+        "-0.00125"
+    );
+    assert_eq!(BigFloat::try_from("1e100").unwrap().to_string(), "1e100");
+
+    let x = BigFloat::parse("0.1", 10, RoundingMode::Down).unwrap();
+    assert_eq!(x, float(819, -13));
+    let x = BigFloat::parse("0.1", 10, RoundingMode::Ceiling).unwrap();
+    assert_eq!(x, float(205, -11));
+
+    // Enough precision for the given digits
+    let digits = "3.1415926535897932384626433832795028841971693993751";
+    let x = BigFloat::try_from(digits).unwrap();
+    assert!(x.precision() > 160);
+    assert_eq!(x.to_decimal(50, RoundingMode::HalfEven), digits);
+
+    assert!(BigFloat::try_from("").is_err());
+    assert!(BigFloat::try_from("1.5e").is_err());
+    assert!(BigFloat::try_from("1..5").is_err());
+
+    // Decimal exponents past 32 bits
+    assert!(BigFloat::try_from("1e4294967296").is_err());
+    assert!(BigFloat::try_from("1e-4294967296").is_err());
+    assert!(BigFloat::parse("1e4294967296", 64, RoundingMode::Down).is_err());
+}
+
+#[test]
+fn bigfloat_display() {
+    assert_eq!(BigFloat::zero().to_string(), "0");
+    assert_eq!(float(1, 100).to_string(), "1.2676506002282294015e30");
+    assert_eq!(float(-3, -1).to_string(), "-1.5");
+    assert_eq!(float(1, -30).to_string(), "9.31322574615478515625e-10");
+    assert_eq!(float(1, 60).to_string(), "1152921504606846976");
+
+    let x = float(1, 0).div_rounded(&float(3, 0), 53, RoundingMode::HalfEven);
+    assert_eq!(x.to_decimal(5, RoundingMode::HalfEven), "0.33333");
+    assert_eq!(x.to_decimal(5, RoundingMode::Ceiling), "0.33334");
+    assert_eq!(float(999, 0).to_decimal(2, RoundingMode::HalfEven), "1000");
+    assert_eq!(float(999, 0).to_decimal(2, RoundingMode::Down), "990");
+
+    // Exponents far past the ones of the digits
+    assert_eq!(
+        float(1, 1 << 40).to_string(),
+        "8.05723224506582382563e330985980541"
+    );
+    assert_eq!(
+        float(1, -(1 << 40)).to_string(),
+        "1.24112098247185434939e-330985980542"
+    );
+    let x = float(3, 1 << 40);
+    assert_eq!(x.to_decimal(5, RoundingMode::Floor), "2.4171e330985980542");
+    assert_eq!(
+        x.to_decimal(5, RoundingMode::Ceiling),
+        "2.4172e330985980542"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Exponent overflow")]
+fn bigfloat_exponent_overflow() {
+    let _ = &float(1, i64::MAX - 1) * &float(1, 2);
+}
+
+#[test]
+fn bigfloat_exp() {
+    let one = float(1, 0);
+    assert_eq!(
+        one.exp_decimal(50, RoundingMode::HalfEven),
+        "2.7182818284590452353602874713526624977572470937"
+    );
+    assert_eq!(
+        one.exp_decimal(30, RoundingMode::Down),
+        "2.71828182845904523536028747135"
+    );
+    assert_eq!(
+        one.exp_decimal(30, RoundingMode::Ceiling),
+        "2.71828182845904523536028747136"
+    );
+    assert_eq!(
+        one.exp_rounded(53, RoundingMode::HalfEven)
+            .to_f64(RoundingMode::HalfEven),
+        std::f64::consts::E
+    );
+    assert_eq!(
+        float(-1, 0).exp_decimal(20, RoundingMode::HalfEven),
+        "0.3678794411714423216"
+    );
+
+    // Exact, even when rounding toward an infinity
+    assert_eq!(BigFloat::zero().exp_rounded(10, RoundingMode::Ceiling), one);
+
+    // e^(2^-1000) is only above 1 toward positive infinity
+    let tiny = float(1, -1000);
+    assert_eq!(tiny.exp_rounded(53, RoundingMode::Floor), one);
+    assert!(tiny.exp_rounded(53, RoundingMode::Ceiling) > one);
+}
+
+#[test]
+fn bigfloat_ln() {
+    assert_eq!(
+        float(10, 0).ln_decimal(40, RoundingMode::HalfEven),
+        "2.302585092994045684017991454684364207601"
+    );
+    assert_eq!(
+        float(2, 0)
+            .ln_rounded(53, RoundingMode::HalfEven)
+            .to_f64(RoundingMode::HalfEven),
+        std::f64::consts::LN_2
+    );
+    assert_eq!(
+        float(3, 0).log2_decimal(30, RoundingMode::HalfEven),
+        "1.58496250072115618145373894395"
+    );
+    assert_eq!(
+        float(2, 0).log10_decimal(30, RoundingMode::Floor),
+        "0.301029995663981195213738894724"
+    );
+
+    // Exact values
+    assert_eq!(
+        float(1, 0).ln_rounded(10, RoundingMode::Floor),
+        BigFloat::zero()
+    );
+    assert_eq!(
+        float(1, -20).log2_rounded(10, RoundingMode::Ceiling),
+        float(-20, 0)
+    );
+    assert_eq!(float(1000, 0).log10_decimal(10, RoundingMode::Floor), "3");
+}
+
+#[test]
+#[should_panic(expected = "Logarithm of a non-positive number")]
+fn bigfloat_ln_of_negative() {
+    let _ = float(-1, 0).ln();
+}
+
+#[test]
+fn bigfloat_trig() {
+    let one = float(1, 0);
+    assert_eq!(
+        one.sin_decimal(40, RoundingMode::HalfEven),
+        "0.8414709848078965066525023216302989996226"
+    );
+    assert_eq!(
+        one.cos_decimal(40, RoundingMode::HalfEven),
+        "0.5403023058681397174009366074429766037323"
+    );
+    assert_eq!(
+        one.tan_decimal(40, RoundingMode::HalfEven),
+        "1.557407724654902230506974807458360173087"
+    );
+    assert_eq!(
+        float(1, -1).atan_decimal(40, RoundingMode::HalfEven),
+        "0.4636476090008061162142562314612144020285"
+    );
+    assert_eq!(
+        one.atan_rounded(53, RoundingMode::HalfEven)
+            .to_f64(RoundingMode::HalfEven),
+        std::f64::consts::FRAC_PI_4
+    );
+
+    // The argument reduction keeps the accuracy for large arguments
+    let large = BigFloat::parse("1e22", 80, RoundingMode::HalfEven).unwrap();
+    assert_eq!(
+        large.sin_decimal(30, RoundingMode::HalfEven),
+        "-0.852200849767188801772705893753"
+    );
+
+    assert_eq!(BigFloat::zero().cos_rounded(10, RoundingMode::Floor), one);
+    assert_eq!(BigFloat::zero().sin(), BigFloat::zero());
+}
+
+#[test]
+fn bigfloat_atan2() {
+    let one = float(1, 0);
+    assert_eq!(
+        float(-1, 0).atan2_decimal(&float(-1, 0), 30, RoundingMode::HalfEven),
+        "-2.35619449019234492884698253746"
+    );
+    assert_eq!(
+        BigFloat::zero()
+            .atan2_rounded(&float(-1, 0), 53, RoundingMode::HalfEven)
+            .to_f64(RoundingMode::HalfEven),
+        std::f64::consts::PI
+    );
+    assert_eq!(
+        one.atan2_rounded(&BigFloat::zero(), 53, RoundingMode::HalfEven)
+            .to_f64(RoundingMode::HalfEven),
+        std::f64::consts::FRAC_PI_2
+    );
+    assert_eq!(BigFloat::zero().atan2(&BigFloat::zero()), BigFloat::zero());
+}
+
+#[test]
+fn bigfloat_hyperbolic() {
+    let half = float(1, -1);
+    assert_eq!(
+        half.sinh_decimal(30, RoundingMode::HalfEven),
+        "0.521095305493747361622425626411"
+    );
+    assert_eq!(
+        half.cosh_decimal(30, RoundingMode::HalfEven),
+        "1.1276259652063807852262251614"
+    );
+    assert_eq!(
+        (-&half).sinh_decimal(30, RoundingMode::HalfEven),
+        "-0.521095305493747361622425626411"
+    );
+}
+
+#[test]
+fn bigfloat_pow() {
+    let three = float(3, 0);
+    assert_eq!(
+        three.pow_decimal(&float(3, -1), 30, RoundingMode::HalfEven),
+        "5.19615242270663188058233902452"
+    );
+    assert_eq!(
+        float(2, 0).pow_decimal(&float(-1, -1), 30, RoundingMode::HalfEven),
+        "0.707106781186547524400844362105"
+    );
+
+    // Exact powers, even when rounding toward an infinity
+    assert_eq!(
+        float(16, 0).pow_rounded(&float(1, -2), 10, RoundingMode::Ceiling),
+        float(2, 0)
+    );
+    assert_eq!(
+        float(-2, 0).pow_rounded(&three, 10, RoundingMode::Floor),
+        float(-8, 0)
+    );
+    assert_eq!(
+        float(5, 0).pow_decimal(&float(-1, 0), 10, RoundingMode::Floor),
+        "0.2"
+    );
+
+    // Powers of two and long exponents stay exact under directed rounding
+    let two = float(2, 0);
+    assert_eq!(
+        two.pow_rounded(&float(1, 20), 53, RoundingMode::Down),
+        float(1, 1 << 20)
+    );
+    assert_eq!(
+        two.pow_rounded(&float(1, 40), 53, RoundingMode::Floor),
+        float(1, 1 << 40)
+    );
+    assert_eq!(
+        float(1, -3).pow_rounded(&float(-1, 30), 53, RoundingMode::Ceiling),
+        float(1, 3 << 30)
+    );
+    assert_eq!(
+        float(-2, 0).pow_rounded(&float((1 << 20) + 1, 0), 53, RoundingMode::Ceiling),
+        float(-1, (1 << 20) + 1)
+    );
+    assert_eq!(
+        float(3, 1 << 30).pow_rounded(&float(2, 0), 53, RoundingMode::Down),
+        float(9, 1 << 31)
+    );
+    assert_eq!(
+        two.pow_decimal(&float(1, 20), 10, RoundingMode::Down),
+        "6.741140125e315652"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Non-integer power of a negative number")]
+fn bigfloat_pow_of_negative() {
+    let _ = float(-2, 0).pow(&float(1, -1));
+}
@@ -1,4 +1,4 @@
-use apnum::{APNum, BigInt};
+use apnum::{APNum, BigInt, BigNat};
 
 // These tests are essentailly for testing sign calculations.
 // All numeric calculations based on BigNat which is tested
@@ -73,6 +73,13 @@ fn bigint_div() {
     let x = BigInt::from(0);
     let y = BigInt::from(2);
     assert_eq!(&x / &y, (BigInt::zero(), BigInt::zero()));
+    let x = BigInt::from(-4);
+    let y = BigInt::from(2);
+    assert_eq!(&x / &y, (BigInt::from(-2), BigInt::zero()));
+    assert_eq!(&-&x / &-&y, (BigInt::from(-2), BigInt::zero()));
+    assert_eq!(&x / 2u32, (BigInt::from(-2), 0));
+    assert_eq!(&x / -2i32, (BigInt::from(2), 0));
+    assert_eq!(&-x / -2i32, (BigInt::from(-2), 0));
 }
 
 #[test]
@@ -97,4 +104,59 @@ fn bigint_cmp() {
     let y = BigInt::from(4);
     assert!(x < y);
     assert!(x > -y);
+    // A zero natural has the zero sign
+    let x = BigInt::from(BigNat::zero());
+    assert_eq!(x, BigInt::zero());
+    assert!(!x.is_positive() && !x.is_negative());
+}
+
+#[test]
+fn bigint_root() {
+    let x = BigInt::from(-27);
+    assert_eq!(x.cbrt(), BigInt::from(-3));
+    let x = BigInt::from(-28);
+    assert_eq!(x.cbrt(), BigInt::from(-3));
+    let x = BigInt::from(-26);
+    assert_eq!(x.nth_root(3), BigInt::from(-2));
+    let x = BigInt::from(-3).pow(41);
+    assert_eq!(x.nth_root(41), BigInt::from(-3));
+    let x = BigInt::from(80);
+    assert_eq!(x.sqrt(), BigInt::from(8));
+    assert_eq!(BigInt::zero().cbrt(), BigInt::zero());
+}
+
+#[test]
+fn bigint_log() {
+    let x = BigInt::from(1025);
+    assert_eq!(x.ilog2(), 10);
+    assert_eq!(x.ilog10(), 3);
+    assert_eq!((-&x).checked_ilog2(), None);
+    assert_eq!(BigInt::zero().checked_ilog10(), None);
+    assert_eq!((-&x).decimal_digit_count(), 4);
+}
+
+#[test]
+fn bigint_sum_product() {
+    let terms: Vec<BigInt> = (-500..=600).map(BigInt::from).collect();
+    assert_eq!(terms.iter().sum::<BigInt>(), BigInt::from(55050));
+    assert_eq!(terms.into_iter().sum::<BigInt>(), BigInt::from(55050));
+    let terms = [BigInt::from(-7), BigInt::from(3), BigInt::from(4)];
+    assert_eq!(terms.iter().sum::<BigInt>(), BigInt::zero());
+    assert!(terms.iter().sum::<BigInt>().is_zero());
+
+    let factors: Vec<BigInt> = (1..=25).map(|n| BigInt::from(-n)).collect();
+    assert_eq!(
+        factors.iter().product::<BigInt>(),
+        BigInt::try_from("-15511210043330985984000000").unwrap()
+    );
+    assert_eq!(
+        factors.into_iter().skip(1).product::<BigInt>(),
+        BigInt::try_from("15511210043330985984000000").unwrap()
+    );
+    let factors = [BigInt::from(-3), BigInt::zero(), BigInt::from(5)];
+    assert!(factors.iter().product::<BigInt>().is_zero());
+    assert_eq!(
+        Vec::<BigInt>::new().into_iter().product::<BigInt>(),
+        BigInt::from(1)
+    );
 }
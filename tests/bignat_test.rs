@@ -33,6 +33,11 @@ fn bignat_mul() {
     let x = BigNat::from(77usize);
     let y = BigNat::from(33usize);
     assert_eq!(x * y, BigNat::from(2541usize));
+    // Long enough for Karatsuba, (2^3200 - 1)^2 = 2^6400 - 2^3201 + 1
+    let one = BigNat::from(1usize);
+    let x = BigNat::try_from((&one << 3200) - 1u8).unwrap();
+    let y = BigNat::try_from((&one << 6400) - (&one << 3201)).unwrap() + 1u8;
+    assert_eq!(&x * &x, y);
 }
 
 #[test]
@@ -74,6 +79,49 @@ fn bignat_div() {
     let x = BigNat::from(4100usize);
     let y = 588u32;
     assert_eq!(&x / y, (BigNat::from(6usize), 572));
+    // Used to loop forever because of the normalization at D1
+    let x = BigNat::try_from("7928357025840609168330687455650589").unwrap();
+    let y = BigNat::from(13872149759015usize);
+    assert_eq!(
+        &x / &y,
+        (
+            BigNat::try_from("571530524365069047151").unwrap(),
+            BigNat::from(11107133334324usize)
+        )
+    );
+    let x = BigNat::zero();
+    let y = 7u32;
+    assert_eq!(&x / y, (BigNat::zero(), 0));
+    // D6 [Add back.] with digits [0, 0, 2^31, 2^31 - 1] / [1, 0, 2^31]
+    let x = BigNat::try_from("170141183420855150474555134919112130560").unwrap();
+    let y = BigNat::try_from("39614081257132168796771975169").unwrap();
+    assert_eq!(
+        &x / &y,
+        (
+            BigNat::from(4294967294usize),
+            BigNat::try_from("39614081257132168792477007874").unwrap()
+        )
+    );
+    // Divisors with every leading digit size, q * y + r = x and r < y
+    let x = BigNat::from(3usize).pow(200);
+    for shift in 0..64 {
+        let y = (BigNat::from(7usize).pow(20) << shift) + 5u8;
+        let (q, r) = &x / &y;
+        assert_eq!(&(&q * &y) + &r, x);
+        assert!(r < y);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Division by Zero")]
+fn bignat_div_by_zero() {
+    let _ = &BigNat::from(5usize) / &BigNat::zero();
+}
+
+#[test]
+#[should_panic(expected = "Division by Zero")]
+fn bignat_div_by_zero_digit() {
+    let _ = &BigNat::from(5usize) / 0u32;
 }
 
 #[test]
@@ -93,4 +141,314 @@ fn bignat_cmp() {
     let x = BigNat::from(0usize);
     let y = BigNat::from(4usize);
     assert!(x < y);
+    assert_eq!(BigNat::zero().cmp_u32(4), std::cmp::Ordering::Less);
+    assert_eq!(BigNat::zero().cmp_u32(0), std::cmp::Ordering::Equal);
+    assert_eq!(BigNat::zero() - 4u32, BigInt::from(-4));
+}
+
+#[test]
+fn bignat_root() {
+    let x = BigNat::zero();
+    assert_eq!(x.sqrt(), BigNat::zero());
+    let x = BigNat::from(99usize);
+    assert_eq!(x.sqrt_rem(), (BigNat::from(9usize), BigNat::from(18usize)));
+    let x = BigNat::from(u64::MAX);
+    assert_eq!(x.sqrt(), BigNat::from(u32::MAX));
+    // (10^20 + 1)^2 - 1 is right below a square
+    let x = BigNat::try_from("10000000000000000000200000000000000000000").unwrap();
+    assert_eq!(x.sqrt(), BigNat::try_from("100000000000000000000").unwrap());
+    let x = BigNat::try_from("1000000000000000000000000000000").unwrap();
+    assert_eq!(x.cbrt(), BigNat::from(10000000000usize));
+    assert_eq!(x.nth_root(5), BigNat::from(1000000usize));
+    assert_eq!(x.nth_root(7), BigNat::from(19306usize));
+    assert_eq!(x.nth_root(200), BigNat::from(1usize));
+}
+
+#[test]
+fn bignat_perfect_power() {
+    assert!(BigNat::zero().is_perfect_square());
+    assert!(BigNat::from(1usize).is_perfect_power());
+    assert!(BigNat::from(144usize).is_perfect_square());
+    assert!(!BigNat::from(145usize).is_perfect_square());
+    assert!(!BigNat::from(u64::MAX).is_perfect_square());
+    assert!(BigNat::from(3usize).pow(40).is_perfect_power());
+    assert!(BigNat::from(7usize).pow(15).is_perfect_power());
+    assert!(!BigNat::from(7usize).pow(15).is_perfect_square());
+    assert!(!(BigNat::from(7usize).pow(15) + 1u32).is_perfect_power());
+    assert!(!BigNat::from(72usize).is_perfect_power());
+}
+
+#[test]
+fn bignat_log() {
+    let x = BigNat::from(1usize);
+    assert_eq!(x.ilog2(), 0);
+    assert_eq!(x.ilog10(), 0);
+    let x = BigNat::from(u64::MAX);
+    assert_eq!(x.ilog2(), 63);
+    assert_eq!(x.ilog10(), 19);
+    let x = BigNat::from(10usize).pow(40);
+    assert_eq!(x.ilog10(), 40);
+    let y = BigNat::try_from("9".repeat(40).as_str()).unwrap();
+    assert_eq!(y.ilog10(), 39);
+    assert_eq!(x.ilog(&BigNat::from(1000usize)), 13);
+    assert_eq!(x.ilog(&BigNat::from(3usize)), 83);
+    assert_eq!(BigNat::from(3usize).pow(83).ilog(&BigNat::from(3usize)), 83);
+    assert_eq!(BigNat::zero().checked_ilog2(), None);
+    assert_eq!(x.checked_ilog(&BigNat::from(1usize)), None);
+    assert_eq!(BigNat::zero().decimal_digit_count(), 1);
+    assert_eq!(x.decimal_digit_count(), 41);
+    assert_eq!(BigNat::from(999usize).decimal_digit_count(), 3);
+}
+
+#[test]
+fn bignat_prime() {
+    assert!(!BigNat::zero().is_probable_prime(0));
+    assert!(!BigNat::from(1usize).is_probable_prime(0));
+    assert!(BigNat::from(2usize).is_probable_prime(0));
+    assert!(BigNat::from(997usize).is_probable_prime(0));
+    assert!(!BigNat::from(561usize).is_probable_prime(0));
+    assert!(BigNat::from(999983usize).is_probable_prime(0));
+    assert!(!BigNat::from(1009usize * 1013).is_probable_prime(0));
+    // Strong pseudoprime to the bases 2, 3, 5, ..., 23
+    assert!(!BigNat::from(3825123056546413051usize).is_probable_prime(0));
+    let mersenne = |p| BigNat::try_from((BigNat::from(1usize) << p) - 1u8).unwrap();
+    assert!(mersenne(61).is_probable_prime(0));
+    assert!(mersenne(89).is_probable_prime(0));
+    assert!(mersenne(127).is_probable_prime(8));
+    assert!(!mersenne(67).is_probable_prime(0));
+    assert!(!(mersenne(89) * mersenne(107)).is_probable_prime(8));
+    // Strong pseudoprimes to the bases 2, 3, 5, ..., 37
+    let x = BigNat::try_from("318665857834031151167461").unwrap();
+    assert!(!x.is_probable_prime(0));
+    let x = BigNat::try_from("3317044064679887385961981").unwrap();
+    assert!(!x.is_probable_prime(0));
+}
+
+#[test]
+fn bignat_next_prime() {
+    assert_eq!(BigNat::zero().next_prime(), BigNat::from(2usize));
+    assert_eq!(BigNat::from(2usize).next_prime(), BigNat::from(3usize));
+    assert_eq!(BigNat::from(7usize).next_prime(), BigNat::from(11usize));
+    assert_eq!(BigNat::from(1usize).prev_prime(), None);
+    assert_eq!(BigNat::from(2usize).prev_prime(), None);
+    assert_eq!(BigNat::from(3usize).prev_prime(), Some(BigNat::from(2usize)));
+    assert_eq!(BigNat::from(4usize).prev_prime(), Some(BigNat::from(3usize)));
+    let x = BigNat::from(10usize).pow(30);
+    assert_eq!(x.next_prime(), &x + 57u8);
+    assert_eq!(x.prev_prime(), Some(BigNat::try_from(&x - 11u8).unwrap()));
+}
+
+#[test]
+fn bignat_random_prime() {
+    // xorshift
+    let mut state = 88172645463325252u64;
+    let mut rng = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as u32
+    };
+
+    for bits in [2, 17, 64, 100] {
+        let prime = BigNat::random_prime(bits, &mut rng);
+        assert_eq!(prime.bits(), bits);
+        assert!(prime.is_probable_prime(4));
+    }
+
+    // Both 2 and 3 come up for 2 bits
+    let primes: Vec<_> = (0..32)
+        .map(|_| BigNat::random_prime(2, &mut rng))
+        .collect();
+    assert!(primes.contains(&BigNat::from(2usize)));
+    assert!(primes.contains(&BigNat::from(3usize)));
+
+    for bits in [3, 40] {
+        let prime = BigNat::random_safe_prime(bits, &mut rng);
+        assert_eq!(prime.bits(), bits);
+        assert!(prime.is_probable_prime(4));
+        assert!((&prime >> 1).is_probable_prime(4));
+    }
+}
+
+#[test]
+fn bignat_primes_between() {
+    let primes = BigNat::primes_between(&BigNat::zero(), &BigNat::from(30usize));
+    let expected: Vec<BigNat> = [2usize, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        .into_iter()
+        .map(BigNat::from)
+        .collect();
+    assert_eq!(primes, expected);
+
+    let primes = BigNat::primes_between(&BigNat::from(1000000usize), &BigNat::from(1001000usize));
+    assert_eq!(primes.len(), 75);
+
+    let low = BigNat::from(10usize).pow(30);
+    let primes = BigNat::primes_between(&low, &(&low + 200u8));
+    assert_eq!(primes, vec![&low + 57u8, &low + 99u8]);
+}
+
+#[test]
+fn bignat_gcd() {
+    let x = BigNat::from(2usize.pow(5) * 3usize.pow(7) * 11);
+    let y = BigNat::from(2usize.pow(9) * 3usize.pow(2) * 13);
+    assert_eq!(x.gcd(&y), BigNat::from(2usize.pow(5) * 3usize.pow(2)));
+    assert_eq!(x.lcm(&y), BigNat::from(2usize.pow(9) * 3usize.pow(7) * 11 * 13));
+    assert_eq!(x.gcd(&BigNat::zero()), x);
+    assert_eq!(BigNat::zero().gcd(&BigNat::zero()), BigNat::zero());
+    assert_eq!(x.lcm(&BigNat::zero()), BigNat::zero());
+}
+
+#[test]
+fn bignat_sqrt_mod() {
+    let nats = |xs: &[usize]| xs.iter().map(|x| BigNat::from(*x)).collect::<Vec<_>>();
+
+    assert_eq!(
+        BigNat::from(3usize).mod_inverse(&BigNat::from(40usize)),
+        Some(BigNat::from(27usize))
+    );
+    assert_eq!(
+        BigNat::from(4usize).mod_inverse(&BigNat::from(40usize)),
+        None
+    );
+
+    // Tonelli–Shanks
+    let x = BigNat::from(10usize);
+    assert_eq!(
+        x.sqrt_mod(&BigNat::from(13usize)),
+        Some((BigNat::from(6usize), BigNat::from(7usize)))
+    );
+    // Cipolla, 65537 - 1 = 2^16
+    let x = BigNat::from(2usize);
+    assert_eq!(
+        x.sqrt_mod(&BigNat::from(65537usize)),
+        Some((BigNat::from(4080usize), BigNat::from(61457usize)))
+    );
+    // p ≡ 3 (mod 4)
+    let x = BigNat::from(5usize);
+    assert_eq!(x.sqrt_mod(&BigNat::from(7usize)), None);
+    let x = BigNat::from(3usize);
+    let p = BigNat::from(10usize).pow(30) + 57u8;
+    assert_eq!(
+        x.sqrt_mod(&p),
+        Some((
+            BigNat::try_from("492767688934650018614948489645").unwrap(),
+            BigNat::try_from("507232311065349981385051510412").unwrap()
+        ))
+    );
+    assert_eq!(p.sqrt_mod(&p), Some((BigNat::zero(), BigNat::zero())));
+
+    let x = BigNat::from(2usize);
+    assert_eq!(
+        x.sqrt_mod_prime_power(&BigNat::from(7usize), 5),
+        Some(nats(&[4567, 12240]))
+    );
+    let x = BigNat::from(17usize);
+    assert_eq!(
+        x.sqrt_mod_prime_power(&BigNat::from(2usize), 10),
+        Some(nats(&[233, 279, 745, 791]))
+    );
+
+    let x = BigNat::from(49usize);
+    let factors = [
+        (BigNat::from(2usize), 3),
+        (BigNat::from(3usize), 2),
+        (BigNat::from(5usize), 1),
+    ];
+    assert_eq!(
+        x.sqrt_mod_composite(&factors),
+        Some(nats(&[
+            7, 43, 47, 83, 97, 133, 137, 173, 187, 223, 227, 263, 277, 313, 317, 353
+        ]))
+    );
+    let x = BigNat::from(31usize);
+    let factors = [
+        (BigNat::from(2usize), 4),
+        (BigNat::from(7usize), 3),
+        (BigNat::from(11usize), 1),
+    ];
+    assert_eq!(x.sqrt_mod_composite(&factors), None);
+}
+
+#[test]
+fn bignat_factorial() {
+    assert_eq!(BigNat::factorial(0), BigNat::from(1usize));
+    assert_eq!(
+        BigNat::factorial(25),
+        BigNat::try_from("15511210043330985984000000").unwrap()
+    );
+
+    let mut expected = BigNat::from(1usize);
+    for n in 1..=2000u32 {
+        expected = expected * n;
+    }
+    assert_eq!(BigNat::factorial(2000), expected);
+
+    assert_eq!(BigNat::double_factorial(0), BigNat::from(1usize));
+    assert_eq!(BigNat::double_factorial(9), BigNat::from(945usize));
+    assert_eq!(BigNat::double_factorial(10), BigNat::from(3840usize));
+    assert_eq!(
+        BigNat::double_factorial(41),
+        BigNat::try_from("13113070457687988603440625").unwrap()
+    );
+}
+
+#[test]
+fn bignat_binomial() {
+    assert_eq!(BigNat::binomial(5, 7), BigNat::zero());
+    assert_eq!(BigNat::binomial(5, 0), BigNat::from(1usize));
+    assert_eq!(BigNat::binomial(50, 25), BigNat::from(126410606437752usize));
+    assert_eq!(
+        BigNat::binomial(1000, 500),
+        (&BigNat::factorial(1000) / &(BigNat::factorial(500) * BigNat::factorial(500))).0
+    );
+    assert_eq!(BigNat::multinomial(&[2, 3, 4]), BigNat::from(1260usize));
+    assert_eq!(BigNat::multinomial(&[]), BigNat::from(1usize));
+}
+
+#[test]
+fn bignat_product_tree() {
+    assert_eq!(BigNat::product(vec![]), BigNat::from(1usize));
+    assert_eq!(
+        BigNat::product(vec![BigNat::from(7usize)]),
+        BigNat::from(7usize)
+    );
+
+    let factors: Vec<BigNat> = (1..=1000u32).map(BigNat::from).collect();
+    assert_eq!(BigNat::product(factors), BigNat::factorial(1000));
+
+    let x = BigNat::factorial(500) + BigNat::from(12345usize);
+    let moduli: Vec<BigNat> = (1..=300u32)
+        .map(|m| BigNat::from(m).pow(7) + BigNat::from(1usize))
+        .chain([BigNat::factorial(600), BigNat::from(1usize)])
+        .collect();
+    let remainders = BigNat::remainder_tree(&x, &moduli);
+    assert_eq!(remainders.len(), moduli.len());
+    for (modulus, remainder) in moduli.iter().zip(&remainders) {
+        assert_eq!(*remainder, (&x / modulus).1);
+    }
+
+    assert_eq!(BigNat::remainder_tree(&x, &[]), vec![]);
+}
+
+#[test]
+fn bignat_sum_product() {
+    let terms: Vec<BigNat> = (0..1000u32).map(|n| BigNat::from(u64::MAX) * n).collect();
+    let expected = BigNat::from(u64::MAX) * 499500u32;
+    assert_eq!(terms.iter().sum::<BigNat>(), expected);
+    assert_eq!(terms.into_iter().sum::<BigNat>(), expected);
+    assert_eq!(
+        Vec::<BigNat>::new().into_iter().sum::<BigNat>(),
+        BigNat::zero()
+    );
+
+    let factors: Vec<BigNat> = (1..=100u32).map(BigNat::from).collect();
+    assert_eq!(factors.iter().product::<BigNat>(), BigNat::factorial(100));
+    assert_eq!(
+        factors.into_iter().product::<BigNat>(),
+        BigNat::factorial(100)
+    );
+    assert_eq!(
+        Vec::<BigNat>::new().into_iter().product::<BigNat>(),
+        BigNat::from(1usize)
+    );
 }
@@ -0,0 +1,105 @@
+use apnum::{APNum, BigInt, BigNat, BigRational};
+
+fn rational(s: &str) -> BigRational {
+    BigRational::try_from(s).unwrap()
+}
+
+#[test]
+fn bigrational_new() {
+    let x = BigRational::new(BigInt::from(-12), BigNat::from(18usize));
+    assert_eq!(x.numerator(), &BigInt::from(-2));
+    assert_eq!(x.denominator(), &BigNat::from(3usize));
+    let x = BigRational::new(BigInt::zero(), BigNat::from(18usize));
+    assert_eq!(x, BigRational::zero());
+    assert_eq!(x.denominator(), &BigNat::from(1usize));
+}
+
+#[test]
+fn bigrational_add() {
+    assert_eq!(rational("1/6") + rational("1/10"), rational("4/15"));
+    assert_eq!(rational("1/3") + rational("1/2"), rational("5/6"));
+    assert_eq!(rational("5/6") + rational("-5/6"), BigRational::zero());
+    assert_eq!(rational("0.125") + rational("7/8"), BigRational::from(1));
+    assert_eq!(rational("-3") + BigRational::zero(), rational("-3"));
+}
+
+#[test]
+fn bigrational_sub() {
+    assert_eq!(rational("1/6") - rational("1/10"), rational("1/15"));
+    assert_eq!(rational("1/2") - rational("3/4"), rational("-1/4"));
+    assert_eq!(rational("7/9") - rational("7/9"), BigRational::zero());
+}
+
+#[test]
+fn bigrational_mul() {
+    assert_eq!(rational("2/3") * rational("9/4"), rational("3/2"));
+    assert_eq!(rational("-2/3") * rational("-3/2"), BigRational::from(1));
+    assert_eq!(rational("-2/3") * rational("5/7"), rational("-10/21"));
+    assert_eq!(rational("2/3") * BigRational::zero(), BigRational::zero());
+}
+
+#[test]
+fn bigrational_div() {
+    assert_eq!(rational("2/3") / rational("4/9"), rational("3/2"));
+    assert_eq!(rational("1/2") / rational("-1/4"), BigRational::from(-2));
+    assert_eq!(BigRational::zero() / rational("5"), BigRational::zero());
+}
+
+#[test]
+#[should_panic(expected = "Division by Zero")]
+fn bigrational_div_by_zero() {
+    let _ = rational("1/2") / BigRational::zero();
+}
+
+#[test]
+fn bigrational_cmp() {
+    assert!(rational("1/3") < rational("0.334"));
+    assert!(rational("-1/3") > rational("-0.334"));
+    assert!(rational("-1/3") < BigRational::zero());
+    assert!(rational("22/7") > rational("3.14159"));
+    assert_eq!(rational("0.5"), rational("2/4"));
+}
+
+#[test]
+fn bigrational_rounding() {
+    let cases = [
+        // x, floor, ceil, trunc, round
+        ("7/2", 3, 4, 3, 4),
+        ("-7/2", -4, -3, -3, -4),
+        ("10/3", 3, 4, 3, 3),
+        ("-10/3", -4, -3, -3, -3),
+        ("-5/3", -2, -1, -1, -2),
+        ("4", 4, 4, 4, 4),
+        ("-4", -4, -4, -4, -4),
+        ("1/3", 0, 1, 0, 0),
+        ("-1/3", -1, 0, 0, 0),
+        ("0", 0, 0, 0, 0),
+    ];
+    for (x, floor, ceil, trunc, round) in cases {
+        let x = rational(x);
+        assert_eq!(x.floor(), BigInt::from(floor));
+        assert_eq!(x.ceil(), BigInt::from(ceil));
+        assert_eq!(x.trunc(), BigInt::from(trunc));
+        assert_eq!(x.round(), BigInt::from(round));
+    }
+}
+
+#[test]
+fn bigrational_pow() {
+    assert_eq!(rational("-2/3").pow(3), rational("-8/27"));
+    assert_eq!(rational("-2/3").pow(-2), rational("9/4"));
+    assert_eq!(rational("-2/3").pow(0), BigRational::from(1));
+    assert_eq!(BigRational::zero().pow(5), BigRational::zero());
+    assert_eq!(rational("-1/2").recip(), BigRational::from(-2));
+}
+
+#[test]
+fn bigrational_display() {
+    assert_eq!(rational("-0.75").to_string(), "-3/4");
+    assert_eq!(rational("10/5").to_string(), "2");
+    assert_eq!(rational("-0").to_string(), "0");
+    assert_eq!(
+        rational("123456789012345678901234567890/11").to_string(),
+        "123456789012345678901234567890/11"
+    );
+}
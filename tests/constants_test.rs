@@ -0,0 +1,59 @@
+use apnum::constants::{
+    catalan_string, e, e_string, euler_gamma_string, ln2_string, pi, pi_string, sqrt2_string,
+};
+use apnum::BigInt;
+
+#[test]
+fn constants_pi() {
+    assert_eq!(pi(0), BigInt::from(3));
+    assert_eq!(pi(10), BigInt::from(31415926535i64));
+    assert_eq!(pi_string(0), "3");
+    assert_eq!(
+        pi_string(60),
+        "3.141592653589793238462643383279502884197169399375105820974944"
+    );
+
+    // The digits of a longer expansion extend the shorter ones
+    let long = pi_string(1000);
+    assert!(long.starts_with(&pi_string(999)));
+    assert!(long.ends_with("64201989"));
+}
+
+#[test]
+fn constants_e() {
+    assert_eq!(e(5), BigInt::from(271828));
+    assert_eq!(
+        e_string(50),
+        "2.71828182845904523536028747135266249775724709369995"
+    );
+}
+
+#[test]
+fn constants_logarithms_and_roots() {
+    assert_eq!(
+        ln2_string(50),
+        "0.69314718055994530941723212145817656807550013436025"
+    );
+    assert_eq!(
+        sqrt2_string(50),
+        "1.41421356237309504880168872420969807856967187537694"
+    );
+}
+
+#[test]
+fn constants_euler_gamma_catalan() {
+    assert_eq!(
+        euler_gamma_string(50),
+        "0.57721566490153286060651209008240243104215933593992"
+    );
+    assert_eq!(
+        catalan_string(50),
+        "0.91596559417721901505460351493238411077414937428167"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Too many digits")]
+fn constants_too_many_digits() {
+    let _ = pi(1 << 32);
+}
@@ -0,0 +1,135 @@
+use apnum::continued_fraction::{best_approximation, convergents, expand, expand_sqrt};
+use apnum::{BigInt, BigNat, BigRational};
+
+fn ints(values: &[i64]) -> Vec<BigInt> {
+    values.iter().map(|&value| BigInt::from(value)).collect()
+}
+
+fn nats(values: &[u64]) -> Vec<BigNat> {
+    values.iter().map(|&value| BigNat::from(value)).collect()
+}
+
+#[test]
+fn continued_fraction_expand() {
+    assert_eq!(
+        expand(&BigInt::from(415), &BigNat::from(93usize)),
+        ints(&[4, 2, 6, 7])
+    );
+    assert_eq!(
+        expand(&BigInt::from(-415), &BigNat::from(93usize)),
+        ints(&[-5, 1, 1, 6, 7])
+    );
+    assert_eq!(expand(&BigInt::from(0), &BigNat::from(5usize)), ints(&[0]));
+    assert_eq!(expand(&BigInt::from(12), &BigNat::from(4usize)), ints(&[3]));
+
+    // Consecutive Fibonacci numbers give all ones
+    let terms = expand(&BigInt::from(6765), &BigNat::from(4181usize));
+    assert_eq!(terms.len(), 18);
+    assert_eq!(terms[17], BigInt::from(2));
+}
+
+#[test]
+#[should_panic(expected = "Division by Zero")]
+fn continued_fraction_expand_by_zero() {
+    expand(&BigInt::from(1), &BigNat::from(0usize));
+}
+
+#[test]
+fn continued_fraction_expand_sqrt() {
+    assert_eq!(
+        expand_sqrt(&BigNat::from(2usize)),
+        (BigNat::from(1usize), nats(&[2]))
+    );
+    assert_eq!(
+        expand_sqrt(&BigNat::from(7usize)),
+        (BigNat::from(2usize), nats(&[1, 1, 1, 4]))
+    );
+    assert_eq!(
+        expand_sqrt(&BigNat::from(61usize)),
+        (
+            BigNat::from(7usize),
+            nats(&[1, 4, 3, 1, 2, 2, 1, 3, 4, 1, 14])
+        )
+    );
+    assert_eq!(
+        expand_sqrt(&BigNat::from(16usize)),
+        (BigNat::from(4usize), vec![])
+    );
+    assert_eq!(
+        expand_sqrt(&BigNat::from(0usize)),
+        (BigNat::from(0usize), vec![])
+    );
+}
+
+#[test]
+fn continued_fraction_convergents() {
+    let values: Vec<_> = convergents(ints(&[4, 2, 6, 7])).collect();
+    assert_eq!(
+        values,
+        vec![
+            (BigInt::from(4), BigInt::from(1)),
+            (BigInt::from(9), BigInt::from(2)),
+            (BigInt::from(58), BigInt::from(13)),
+            (BigInt::from(415), BigInt::from(93)),
+        ]
+    );
+
+    // The fundamental solution of x^2 - 61 y^2 = 1 is the convergent at the end of the second
+    // period, as the period is odd
+    let n = BigNat::from(61usize);
+    let (a0, period) = expand_sqrt(&n);
+    let terms = std::iter::once(a0).chain(period.iter().cloned().cycle());
+    let (x, y) = convergents(terms).nth(2 * period.len() - 1).unwrap();
+    assert_eq!(x, BigInt::from(1766319049u64));
+    assert_eq!(y, BigInt::from(226153980u64));
+    assert_eq!(
+        &(&x * &x) - &(&(&y * &y) * &BigInt::from(n)),
+        BigInt::from(1)
+    );
+}
+
+#[test]
+fn continued_fraction_best_approximation() {
+    let pi = BigRational::new(
+        BigInt::from(3141592653589793u64),
+        BigNat::from(1000000000000000u64),
+    );
+    let cases = [
+        (1u64, 3i64, 1u64),
+        (10, 22, 7),
+        (100, 311, 99),
+        (1000, 355, 113),
+        (30000, 94053, 29938),
+    ];
+    for (bound, numerator, denominator) in cases {
+        assert_eq!(
+            best_approximation(&pi, &BigNat::from(bound)),
+            BigRational::new(BigInt::from(numerator), BigNat::from(denominator))
+        );
+    }
+
+    let negative = BigRational::new(BigInt::from(-1234567), BigNat::from(1000000usize));
+    assert_eq!(
+        best_approximation(&negative, &BigNat::from(7usize)),
+        BigRational::new(BigInt::from(-5), BigNat::from(4usize))
+    );
+    assert_eq!(
+        best_approximation(&negative, &BigNat::from(100usize)),
+        BigRational::new(BigInt::from(-100), BigNat::from(81usize))
+    );
+
+    // Within the bound already, and ties going to the smaller denominator
+    let third = BigRational::new(BigInt::from(1), BigNat::from(3usize));
+    assert_eq!(best_approximation(&third, &BigNat::from(3usize)), third);
+    assert_eq!(
+        best_approximation(&third, &BigNat::from(2usize)),
+        BigRational::new(BigInt::from(1), BigNat::from(2usize))
+    );
+    assert_eq!(
+        best_approximation(
+            &BigRational::new(BigInt::from(3), BigNat::from(4usize)),
+            &BigNat::from(2usize)
+        ),
+        BigRational::from(1)
+    );
+}
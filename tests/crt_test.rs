@@ -0,0 +1,67 @@
+use apnum::crt::{crt, garner};
+use apnum::{BigInt, BigNat};
+
+#[test]
+fn crt_coprime() {
+    let residues = [
+        (BigInt::from(2), BigNat::from(3usize)),
+        (BigInt::from(3), BigNat::from(5usize)),
+        (BigInt::from(2), BigNat::from(7usize)),
+    ];
+    assert_eq!(
+        crt(&residues),
+        Some((BigInt::from(23), BigNat::from(105usize)))
+    );
+
+    // Negative and unreduced residues
+    let residues = [
+        (BigInt::from(-1), BigNat::from(4usize)),
+        (BigInt::from(17), BigNat::from(9usize)),
+    ];
+    assert_eq!(
+        crt(&residues),
+        Some((BigInt::from(35), BigNat::from(36usize)))
+    );
+
+    assert_eq!(crt(&[]), Some((BigInt::from(0), BigNat::from(1usize))));
+}
+
+#[test]
+fn crt_not_coprime() {
+    let residues = [
+        (BigInt::from(5), BigNat::from(12usize)),
+        (BigInt::from(11), BigNat::from(18usize)),
+    ];
+    assert_eq!(
+        crt(&residues),
+        Some((BigInt::from(29), BigNat::from(36usize)))
+    );
+
+    let residues = [
+        (BigInt::from(5), BigNat::from(12usize)),
+        (BigInt::from(10), BigNat::from(18usize)),
+    ];
+    assert_eq!(crt(&residues), None);
+
+    let m = BigNat::from(10usize).pow(20);
+    let residues = [
+        (BigInt::from(7), &m * 6u8),
+        (BigInt::from(7), &m * 10u8),
+        (BigInt::from(7), m.clone()),
+    ];
+    assert_eq!(crt(&residues), Some((BigInt::from(7), &m * 30u8)));
+}
+
+#[test]
+fn crt_garner() {
+    assert_eq!(garner(&[(2, 3), (3, 5), (2, 7)]), BigNat::from(23usize));
+
+    // x = 10^30 + 57 modulo primes around 2^32
+    let x = BigNat::from(10usize).pow(30) + 57u8;
+    let moduli = [4294967291u32, 4294967279, 4294967231, 4294967197];
+    let residues: Vec<(u32, u32)> = moduli.iter().map(|m| ((&x / *m).1, *m)).collect();
+    assert_eq!(garner(&residues), x);
+
+    let residues = [(0, 1), (1, 2)];
+    assert_eq!(garner(&residues), BigNat::from(1usize));
+}
@@ -0,0 +1,35 @@
+use apnum::{factor::factor, BigNat};
+
+fn factors(pairs: &[(u64, u32)]) -> Vec<(BigNat, u32)> {
+    pairs
+        .iter()
+        .map(|(prime, exponent)| (BigNat::from(*prime), *exponent))
+        .collect()
+}
+
+#[test]
+fn factor_small() {
+    assert_eq!(factor(&BigNat::from(1usize)), vec![]);
+    assert_eq!(factor(&BigNat::from(2usize)), factors(&[(2, 1)]));
+    assert_eq!(
+        factor(&BigNat::from(2usize.pow(10) * 3usize.pow(5) * 16381)),
+        factors(&[(2, 10), (3, 5), (16381, 1)])
+    );
+}
+
+#[test]
+fn factor_large() {
+    // Fermat number F6, p - 1 is smooth for 274177
+    let x = (BigNat::from(1usize) << 64) + 1u8;
+    assert_eq!(factor(&x), factors(&[(274177, 1), (67280421310721, 1)]));
+
+    // p - 1 isn't smooth for any of them, left to rho
+    let x = BigNat::from(1000003usize) * BigNat::from(1000000000039usize).pow(2);
+    assert_eq!(factor(&x), factors(&[(1000003, 1), (1000000000039, 2)]));
+
+    let x = BigNat::from(1000003usize).pow(3) * BigNat::from(2000003usize);
+    assert_eq!(factor(&x), factors(&[(1000003, 3), (2000003, 1)]));
+
+    let m89 = BigNat::try_from((BigNat::from(1usize) << 89) - 1u8).unwrap();
+    assert_eq!(factor(&m89), vec![(m89.clone(), 1)]);
+}
@@ -0,0 +1,70 @@
+use apnum::{APNum, BigInt, BigNat, GaussianInt};
+
+fn gaussian(real: i64, imaginary: i64) -> GaussianInt {
+    GaussianInt::from((real, imaginary))
+}
+
+#[test]
+fn gaussian_arithmetic() {
+    let a = gaussian(3, -4);
+    let b = gaussian(-2, 5);
+    assert_eq!(&a + &b, gaussian(1, 1));
+    assert_eq!(&a - &b, gaussian(5, -9));
+    assert_eq!(&a * &b, gaussian(14, 23));
+    assert_eq!(-&a, gaussian(-3, 4));
+    assert_eq!(&GaussianInt::i() * &GaussianInt::i(), gaussian(-1, 0));
+    assert_eq!(a.norm(), BigNat::from(25usize));
+    assert_eq!(a.conjugate(), gaussian(3, 4));
+    assert_eq!(&a * &a.conjugate(), GaussianInt::from(BigInt::from(25)));
+    assert!(gaussian(0, -1).is_unit());
+    assert!(!gaussian(1, 1).is_unit());
+}
+
+#[test]
+fn gaussian_div() {
+    let (q, r) = &gaussian(27, -23) / &gaussian(8, 1);
+    assert_eq!(q, gaussian(3, -3));
+    assert_eq!(r, gaussian(0, -2));
+
+    let a = GaussianInt::new(
+        "1000000000000000000000000000007".parse().unwrap(),
+        "-300000000000000000000000000000".parse().unwrap(),
+    );
+    let b = gaussian(12345678901, -98765);
+    let (q, r) = &a / &b;
+    assert_eq!(
+        q,
+        GaussianInt::new(
+            "81000195124508045939".parse().unwrap(),
+            "-24299352220429868441".parse().unwrap()
+        )
+    );
+    assert_eq!(r, gaussian(2105542333, -583371324));
+    assert!(&r.norm() + &r.norm() <= b.norm());
+    assert_eq!(&(&q * &b) + &r, a);
+}
+
+#[test]
+#[should_panic(expected = "Division by Zero")]
+fn gaussian_div_by_zero() {
+    let _ = &gaussian(1, 1) / &GaussianInt::zero();
+}
+
+#[test]
+fn gaussian_gcd() {
+    assert_eq!(gaussian(11, 3).gcd(&gaussian(1, 8)), gaussian(2, 1));
+    assert_eq!(gaussian(4, 2).gcd(&gaussian(3, 1)), gaussian(1, 1));
+    assert_eq!(gaussian(5, 0).gcd(&gaussian(3, 4)), gaussian(2, 1));
+    assert_eq!(GaussianInt::zero().gcd(&gaussian(0, -3)), gaussian(3, 0));
+    assert_eq!(gaussian(-1, -2).normalized(), gaussian(2, -1).normalized());
+}
+
+#[test]
+fn gaussian_display() {
+    assert_eq!(gaussian(3, -4).to_string(), "3-4i");
+    assert_eq!(gaussian(2, 1).to_string(), "2+i");
+    assert_eq!(gaussian(0, -1).to_string(), "-i");
+    assert_eq!(gaussian(0, 7).to_string(), "7i");
+    assert_eq!(gaussian(-5, 0).to_string(), "-5");
+    assert_eq!(GaussianInt::zero().to_string(), "0");
+}
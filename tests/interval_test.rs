@@ -0,0 +1,103 @@
+use apnum::{BigFloat, BigInt, BigRational, Interval, RoundingMode};
+
+fn rational(s: &str) -> BigRational {
+    s.parse().unwrap()
+}
+
+fn exact(lower: &str, upper: &str) -> Interval<BigRational> {
+    Interval::new(rational(lower), rational(upper))
+}
+
+fn float(x: i64, precision: usize) -> BigFloat {
+    BigFloat::new(BigInt::from(x), 0, precision, RoundingMode::HalfEven)
+}
+
+#[test]
+fn interval_rational() {
+    let x = exact("1", "2");
+    let y = exact("-3", "4");
+    assert_eq!(&x + &y, exact("-2", "6"));
+    assert_eq!(&x - &y, exact("-3", "5"));
+    assert_eq!(&x * &y, exact("-6", "8"));
+    assert_eq!(&y * &y, exact("-12", "16"));
+    assert_eq!(&x / &exact("3", "4"), exact("1/4", "2/3"));
+    assert_eq!(&x / &exact("-4", "-1/2"), exact("-4", "-1/4"));
+    assert_eq!(y.width(), rational("7"));
+    assert_eq!(y.midpoint(), rational("1/2"));
+    assert!(y.contains(&rational("-3")) && !y.contains(&rational("41/10")));
+    assert!(y.contains_interval(&x) && !x.contains_interval(&y));
+    assert_eq!(x.to_string(), "[1, 2]");
+    assert_eq!(Interval::point(rational("1/3")).width(), rational("0"));
+}
+
+#[test]
+#[should_panic(expected = "Division by Zero")]
+fn interval_div_by_zero() {
+    let _ = &exact("1", "2") / &exact("-1", "1");
+}
+
+#[test]
+#[should_panic(expected = "Empty interval")]
+fn interval_empty() {
+    exact("2", "1");
+}
+
+#[test]
+fn interval_float() {
+    // 1/3 at 10 bits, enclosed by two neighbouring floats
+    let one = Interval::point(float(1, 10));
+    let third = &one / &Interval::point(float(3, 10));
+    let exact_third = rational("1/3");
+    assert!(BigRational::from(third.lower()) < exact_third);
+    assert!(exact_third < BigRational::from(third.upper()));
+    assert_eq!(BigRational::from(&third.width()), rational("1/2048"));
+    assert!(third.contains(&third.midpoint()));
+
+    // 3 * (1/3) - 1 is not exactly zero but is enclosed
+    let three = Interval::point(float(3, 10));
+    let error = &(&three * &third) - &one;
+    assert!(error.contains(&float(0, 10)));
+    assert!(BigRational::from(&error.width()) <= rational("1/256"));
+
+    // Sums far apart in magnitude round outward too
+    let tiny = Interval::point(BigFloat::new(BigInt::from(1), -100, 10, RoundingMode::Down));
+    let sum = &one + &tiny;
+    assert_eq!(sum.lower(), &float(1, 10));
+    assert!(sum.upper() > &float(1, 10));
+}
+
+#[test]
+fn interval_sqrt() {
+    let two = Interval::point(float(2, 64));
+    let root = two.sqrt();
+    let (lower, upper) = (
+        BigRational::from(root.lower()),
+        BigRational::from(root.upper()),
+    );
+    assert!(&lower * &lower < rational("2") && rational("2") < &upper * &upper);
+    assert_eq!(
+        BigRational::from(&root.width()),
+        rational("1/9223372036854775808")
+    );
+    assert_eq!(
+        Interval::point(float(9, 64)).sqrt(),
+        Interval::point(float(3, 64))
+    );
+
+    let root = exact("2", "9/4").sqrt(64);
+    assert!(root.lower() * root.lower() < rational("2"));
+    assert_eq!(root.upper(), &rational("3/2"));
+    assert!(root.contains(&rational("1.4142135623730950488")));
+
+    // Straddling zero, the negative part has no square root
+    let root = Interval::new(float(-1, 32), float(4, 32)).sqrt();
+    assert_eq!(BigRational::from(root.lower()), rational("0"));
+    assert_eq!(BigRational::from(root.upper()), rational("2"));
+    assert_eq!(exact("-1", "4").sqrt(32), exact("0", "2"));
+}
+
+#[test]
+#[should_panic(expected = "Square root of a negative number")]
+fn interval_sqrt_negative() {
+    exact("-4", "-1").sqrt(32);
+}
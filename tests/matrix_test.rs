@@ -0,0 +1,235 @@
+use apnum::{BigInt, BigNat, BigRational, Matrix};
+
+fn matrix(rows: &[&[i64]]) -> Matrix<BigInt> {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|row| row.iter().map(|&x| BigInt::from(x)).collect())
+            .collect(),
+    )
+}
+
+#[test]
+fn matrix_arithmetic() {
+    let a = matrix(&[
+        &[2, -3, 5, 7],
+        &[1, 4, -6, 0],
+        &[3, 1, -1, 7],
+        &[0, 11, -17, -7],
+    ]);
+    let b = matrix(&[
+        &[3, -1, 4, 1],
+        &[5, 9, -2, 6],
+        &[5, 3, 5, -8],
+        &[9, 7, -9, 3],
+    ]);
+    assert_eq!(
+        &a * &b,
+        matrix(&[
+            &[79, 35, -24, -35],
+            &[-7, 17, -34, 73],
+            &[72, 52, -58, 38],
+            &[-93, -1, -44, 181]
+        ])
+    );
+    assert_eq!(&(&a + &b) - &b, a);
+    assert_eq!(&a * &Matrix::identity(4), a);
+    assert_eq!(
+        matrix(&[&[1, 2, 3], &[4, 5, 6]]).transpose(),
+        matrix(&[&[1, 4], &[2, 5], &[3, 6]])
+    );
+    assert_eq!(
+        a.to_string(),
+        "[[2, -3, 5, 7], [1, 4, -6, 0], [3, 1, -1, 7], [0, 11, -17, -7]]"
+    );
+    assert_eq!(a[(3, 1)], BigInt::from(11));
+}
+
+#[test]
+#[should_panic(expected = "Dimension mismatch")]
+fn matrix_mul_mismatch() {
+    let _ = &matrix(&[&[1, 2]]) * &matrix(&[&[1, 2]]);
+}
+
+#[test]
+fn matrix_determinant_rank() {
+    let a = matrix(&[
+        &[2, -3, 5, 7],
+        &[1, 4, -6, 0],
+        &[3, 1, -1, 7],
+        &[0, 11, -17, -7],
+    ]);
+    let b = matrix(&[
+        &[3, -1, 4, 1],
+        &[5, 9, -2, 6],
+        &[5, 3, 5, -8],
+        &[9, 7, -9, 3],
+    ]);
+    assert_eq!(a.determinant(), BigInt::from(0));
+    assert_eq!(a.rank(), 2);
+    assert_eq!(b.determinant(), BigInt::from(-5040));
+    assert_eq!(b.rank(), 4);
+    assert_eq!(matrix(&[&[0, 1], &[1, 0]]).determinant(), BigInt::from(-1));
+    assert_eq!(Matrix::<BigInt>::zero(0, 0).determinant(), BigInt::from(1));
+    assert_eq!(matrix(&[&[1, 2, 3], &[2, 4, 6]]).rank(), 1);
+    assert_eq!(Matrix::<BigInt>::zero(3, 2).rank(), 0);
+
+    // Entries past i64, (i + 2)^(j + 7) - (3j + 1)^(i + 5)
+    let entries = (0..36u32)
+        .map(|k| {
+            let (i, j) = (k / 6, k % 6);
+            &BigInt::from(BigNat::from(i + 2).pow(j + 7))
+                - &BigInt::from(BigNat::from(3 * j + 1).pow(i + 5))
+        })
+        .collect();
+    let big = Matrix::new(6, 6, entries);
+    assert_eq!(
+        big.determinant(),
+        "66560016186692688461573661626326892544".parse().unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "Non-square matrix")]
+fn matrix_determinant_non_square() {
+    matrix(&[&[1, 2, 3], &[4, 5, 6]]).determinant();
+}
+
+#[test]
+fn matrix_hermite_normal_form() {
+    let a = matrix(&[&[3, 3, 1, 4], &[0, 1, 0, 0], &[0, 0, 19, 16], &[0, 0, 0, 3]]);
+    let h = matrix(&[&[3, 0, 1, 1], &[0, 1, 0, 0], &[0, 0, 19, 1], &[0, 0, 0, 3]]);
+    assert_eq!(a.hermite_normal_form(), h);
+
+    // The same lattice in another basis has the same form
+    let u = matrix(&[&[2, 1, 0, -3], &[1, 1, 0, 0], &[0, 4, 1, 7], &[-1, 0, 0, 2]]);
+    assert_eq!(u.determinant(), BigInt::from(-1));
+    assert_eq!((&u * &a).hermite_normal_form(), h);
+
+    // Dependent rows become zero rows
+    assert_eq!(
+        matrix(&[&[2, 4], &[3, 5], &[-1, -1]]).hermite_normal_form(),
+        matrix(&[&[1, 1], &[0, 2], &[0, 0]])
+    );
+}
+
+#[test]
+fn matrix_smith_normal_form() {
+    let c = matrix(&[&[2, 4, 4], &[-6, 6, 12], &[10, -4, -16]]);
+    assert_eq!(
+        c.smith_normal_form(),
+        matrix(&[&[2, 0, 0], &[0, 6, 0], &[0, 0, 12]])
+    );
+
+    let h = matrix(&[&[2, 3, 6, 2], &[5, 6, 1, 6], &[8, 3, 1, 1]]);
+    assert_eq!(
+        h.smith_normal_form(),
+        matrix(&[&[1, 0, 0, 0], &[0, 1, 0, 0], &[0, 0, 1, 0]])
+    );
+
+    // Z/4 + Z/6 = Z/2 + Z/12
+    assert_eq!(
+        matrix(&[&[4, 0], &[0, 6], &[0, 0]]).smith_normal_form(),
+        matrix(&[&[2, 0], &[0, 12], &[0, 0]])
+    );
+}
+
+#[test]
+fn matrix_solve() {
+    let b = matrix(&[
+        &[3, -1, 4, 1],
+        &[5, 9, -2, 6],
+        &[5, 3, 5, -8],
+        &[9, 7, -9, 3],
+    ]);
+    let rhs: Vec<_> = (1..=4).map(BigInt::from).collect();
+    let expected: Vec<BigRational> = ["209/504", "7/120", "-5/252", "-67/630"]
+        .iter()
+        .map(|x| x.parse().unwrap())
+        .collect();
+    assert_eq!(b.solve(&rhs), Some(expected));
+
+    // Consistent and singular, the free variable is zero
+    let a = matrix(&[&[1, 2, 3], &[2, 4, 7]]);
+    let rhs = [BigInt::from(1), BigInt::from(3)];
+    assert_eq!(
+        a.solve(&rhs),
+        Some(vec![
+            BigRational::from(-2),
+            BigRational::from(0),
+            BigRational::from(1)
+        ])
+    );
+
+    // Inconsistent
+    let a = matrix(&[&[1, 2], &[2, 4]]);
+    assert_eq!(a.solve(&[BigInt::from(1), BigInt::from(3)]), None);
+}
+
+#[test]
+fn matrix_lll() {
+    let basis = matrix(&[&[1, 1, 1], &[-1, 0, 2], &[3, 5, 6]]);
+    let reduced = matrix(&[&[0, 1, 0], &[1, 0, 1], &[-1, 0, 2]]);
+    assert_eq!(basis.lll(), reduced);
+    assert_eq!(reduced.lll(), reduced);
+    assert_eq!(basis.lll().determinant().abs(), basis.determinant().abs());
+
+    // Integer relation: x^4 - 10 x^2 + 1 for α = √2 + √3, from α^i 2^k with rows (e_i, α^i 2^k)
+    let k = 120;
+    let scale = BigInt::from(BigNat::from(1usize) << k);
+    let alpha = &BigInt::from((BigNat::from(2usize) << (2 * k)).sqrt())
+        + &BigInt::from((BigNat::from(3usize) << (2 * k)).sqrt());
+    let mut power = scale.clone();
+    let mut rows = vec![];
+    for i in 0..5 {
+        let mut row = vec![BigInt::from(0); 6];
+        row[i] = BigInt::from(1);
+        row[5] = power.clone();
+        power = (&(&power * &alpha) / &scale).0;
+        rows.push(row);
+    }
+    let reduced = Matrix::from_rows(rows).lll();
+    let relation: Vec<_> = reduced.row(0)[..5].to_vec();
+    let expected: Vec<_> = [1, 0, -10, 0, 1].map(BigInt::from).to_vec();
+    let negated: Vec<_> = expected.iter().map(|x| -x).collect();
+    assert!(relation == expected || relation == negated);
+
+    // Knapsack: 2 e_i and N a_i against (1, ..., 1, N s), the solution is a row of ±1 and 0
+    let weights: [i64; 8] = [
+        366_870_457_441,
+        215_812_903_397,
+        459_162_848_181,
+        124_709_561_903,
+        412_871_540_839,
+        305_672_014_913,
+        178_239_950_011,
+        497_316_842_273,
+    ];
+    let chosen = [1, 0, 1, 1, 0, 0, 1, 0];
+    let target: i64 = weights.iter().zip(chosen).map(|(w, c)| w * c).sum();
+    let n = weights.len();
+    let mut rows = vec![];
+    for (i, &weight) in weights.iter().enumerate() {
+        let mut row = vec![0; n + 1];
+        row[i] = 2;
+        row[n] = 1000 * weight;
+        rows.push(row);
+    }
+    let mut last = vec![1; n + 1];
+    last[n] = 1000 * target;
+    rows.push(last);
+    let rows: Vec<&[i64]> = rows.iter().map(|row| row.as_slice()).collect();
+    let reduced = matrix(&rows).lll();
+    let solution: Vec<_> = chosen.iter().map(|c| BigInt::from(2 * c - 1)).collect();
+    let negated: Vec<_> = solution.iter().map(|x| -x).collect();
+    let found = (0..reduced.rows()).any(|i| {
+        let row = reduced.row(i);
+        row[n] == BigInt::from(0) && (row[..n] == solution[..] || row[..n] == negated[..])
+    });
+    assert!(found);
+}
+
+#[test]
+#[should_panic(expected = "Linearly dependent rows")]
+fn matrix_lll_dependent() {
+    matrix(&[&[1, 2], &[2, 4]]).lll();
+}
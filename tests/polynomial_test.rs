@@ -0,0 +1,144 @@
+use apnum::{APNum, BigInt, BigNat, BigRational, Polynomial};
+
+fn poly(coefficients: &[i64]) -> Polynomial<BigInt> {
+    Polynomial::new(coefficients.iter().map(|&c| BigInt::from(c)).collect())
+}
+
+fn rational(coefficients: &[(i64, u64)]) -> Polynomial<BigRational> {
+    let coefficients = coefficients
+        .iter()
+        .map(|&(n, d)| BigRational::new(BigInt::from(n), BigNat::from(d)))
+        .collect();
+    Polynomial::new(coefficients)
+}
+
+#[test]
+fn polynomial_arithmetic() {
+    let a = poly(&[-7, 1, 0, -2, 3]);
+    let b = poly(&[2, 4, -5]);
+    assert_eq!(&a + &b, poly(&[-5, 5, -5, -2, 3]));
+    assert_eq!(&a - &b, poly(&[-9, -3, 5, -2, 3]));
+    assert_eq!(&a * &b, poly(&[-14, -26, 39, -9, -2, 22, -15]));
+    assert_eq!(-&b, poly(&[-2, -4, 5]));
+    assert_eq!(&a - &a, Polynomial::zero());
+    assert_eq!(&a * &Polynomial::zero(), Polynomial::zero());
+    assert_eq!(a.degree(), Some(4));
+    assert_eq!(Polynomial::<BigInt>::zero().degree(), None);
+    assert_eq!(poly(&[1, 2, 0, 0]).degree(), Some(1));
+    assert_eq!(a.derivative(), poly(&[1, 0, -6, 12]));
+    assert_eq!(a.evaluate(&BigInt::from(-2)), BigInt::from(55));
+    assert_eq!(a.to_string(), "3*x^4 - 2*x^3 + x - 7");
+    assert_eq!(poly(&[0, -1]).to_string(), "-x");
+
+    let r = rational(&[(2, 1), (-3, 4), (0, 1), (1, 2)]);
+    assert_eq!(r.to_string(), "1/2*x^3 - 3/4*x + 2");
+    assert_eq!(
+        &r * &rational(&[(1, 3), (2, 1)]),
+        rational(&[(2, 3), (15, 4), (-3, 2), (1, 6), (1, 1)])
+    );
+}
+
+#[test]
+fn polynomial_mul_large() {
+    // Kronecker substitution against the schoolbook product, with mixed signs and sizes
+    let mut seed = 0x2545f4914f6cdd1du64;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+    for (m, n) in [(1, 1), (5, 40), (37, 23), (64, 64)] {
+        let mut random = |length: usize| -> Vec<BigInt> {
+            (0..length)
+                .map(|_| {
+                    let words = next() % 4;
+                    let mut c = BigInt::from(next() % 3) - BigInt::from(1);
+                    for _ in 0..words {
+                        c = &(&c * &BigInt::from(u64::MAX)) + &BigInt::from(next() as i64);
+                    }
+                    c
+                })
+                .collect()
+        };
+        let (a, b) = (random(m), random(n));
+        let mut expected = vec![BigInt::zero(); m + n - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                expected[i + j] = &expected[i + j] + &(x * y);
+            }
+        }
+        assert_eq!(
+            &Polynomial::new(a) * &Polynomial::new(b),
+            Polynomial::new(expected)
+        );
+    }
+}
+
+#[test]
+fn polynomial_division() {
+    let a = poly(&[-7, 1, 0, -2, 3]);
+    let b = poly(&[2, 4, -5]);
+    let (q, r) = a.pseudo_div(&b);
+    assert_eq!(q, poly(&[38, 10, 75]));
+    assert_eq!(r, poly(&[799, -297]));
+    assert_eq!(&a * &poly(&[-125]), &(&q * &b) + &r, "lc(b)^3 a = q b + r");
+    assert_eq!(b.pseudo_div(&a), (Polynomial::zero(), b.clone()));
+
+    let a = rational(&[(2, 1), (-3, 4), (0, 1), (1, 2)]);
+    let b = rational(&[(1, 5), (0, 1), (-2, 3)]);
+    let (q, r) = &a / &b;
+    assert_eq!(q, rational(&[(0, 1), (-3, 4)]));
+    assert_eq!(r, rational(&[(2, 1), (-3, 5)]));
+}
+
+#[test]
+#[should_panic(expected = "Division by Zero")]
+fn polynomial_division_by_zero() {
+    poly(&[1, 2]).pseudo_div(&Polynomial::zero());
+}
+
+#[test]
+fn polynomial_content_gcd() {
+    let f = poly(&[-90, -330, -258, 54, -48, 24]);
+    let g = poly(&[84, 128, -76, 8]);
+    assert_eq!(f.content(), BigNat::from(6usize));
+    assert_eq!(poly(&[-4, 6, -8]).primitive_part(), poly(&[2, -3, 4]));
+    assert_eq!(f.gcd(&g), poly(&[-6, -10, 4]));
+    assert_eq!(g.gcd(&f), poly(&[-6, -10, 4]));
+    assert_eq!(f.gcd(&Polynomial::zero()), f);
+    assert_eq!((-&g).gcd(&Polynomial::zero()), g);
+
+    // Knuth's example, coprime with large pseudo-remainders
+    let u = poly(&[-5, 2, 8, -3, -3, 0, 1, 0, 1]);
+    let v = poly(&[21, -9, -4, 0, 5, 0, 3]);
+    assert_eq!(u.gcd(&v), poly(&[1]));
+
+    // Over the rationals the gcd is monic
+    let f = Polynomial::<BigRational>::from(&f);
+    let g = Polynomial::<BigRational>::from(&g);
+    assert_eq!(f.gcd(&g), rational(&[(-3, 2), (-5, 2), (1, 1)]));
+}
+
+#[test]
+fn polynomial_integer_roots() {
+    // x (x + 12) (x - 1)^2 (x - 35) (x^2 + x + 1)
+    let p = poly(&[0, -420, 397, 24, 419, -397, -24, 1]);
+    assert_eq!(
+        p.integer_roots(),
+        vec![
+            BigInt::from(-12),
+            BigInt::from(0),
+            BigInt::from(1),
+            BigInt::from(35)
+        ]
+    );
+    assert_eq!(poly(&[1, 0, 1]).integer_roots(), vec![]);
+    assert_eq!(
+        poly(&[6, -5, 1]).integer_roots(),
+        vec![BigInt::from(2), BigInt::from(3)]
+    );
+
+    // Not monic, 2x - 1 has no integer root
+    assert_eq!(poly(&[-3, 5, 2]).integer_roots(), vec![BigInt::from(-3)]);
+}
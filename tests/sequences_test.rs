@@ -0,0 +1,82 @@
+use apnum::sequences::{
+    bernoulli, catalan, fibonacci, lucas, partition_count, primorial, stirling2,
+};
+use apnum::{BigNat, BigRational};
+
+fn nat(s: &str) -> BigNat {
+    BigNat::try_from(s).unwrap()
+}
+
+#[test]
+fn sequences_fibonacci() {
+    assert_eq!(fibonacci(0), BigNat::from(0usize));
+    assert_eq!(fibonacci(1), BigNat::from(1usize));
+    assert_eq!(fibonacci(10), BigNat::from(55usize));
+    assert_eq!(
+        fibonacci(300),
+        nat("222232244629420445529739893461909967206666939096499764990979600")
+    );
+
+    assert_eq!(lucas(0), BigNat::from(2usize));
+    assert_eq!(lucas(1), BigNat::from(1usize));
+    assert_eq!(lucas(10), BigNat::from(123usize));
+    assert_eq!(
+        lucas(200),
+        nat("627376215338105766356982006981782561278127")
+    );
+}
+
+#[test]
+fn sequences_catalan() {
+    assert_eq!(catalan(0), BigNat::from(1usize));
+    assert_eq!(catalan(5), BigNat::from(42usize));
+    assert_eq!(
+        catalan(100),
+        nat("896519947090131496687170070074100632420837521538745909320")
+    );
+}
+
+#[test]
+fn sequences_primorial() {
+    assert_eq!(primorial(0), BigNat::from(1usize));
+    assert_eq!(primorial(10), BigNat::from(210usize));
+    assert_eq!(primorial(100), nat("2305567963945518424753102147331756070"));
+}
+
+#[test]
+fn sequences_stirling2() {
+    assert_eq!(stirling2(0, 0), BigNat::from(1usize));
+    assert_eq!(stirling2(5, 0), BigNat::from(0usize));
+    assert_eq!(stirling2(3, 5), BigNat::from(0usize));
+    assert_eq!(stirling2(10, 3), BigNat::from(9330usize));
+    assert_eq!(
+        stirling2(50, 7),
+        nat("355716059292752464797065038013137686280")
+    );
+}
+
+#[test]
+fn sequences_partition_count() {
+    assert_eq!(partition_count(0), BigNat::from(1usize));
+    assert_eq!(partition_count(5), BigNat::from(7usize));
+    assert_eq!(partition_count(100), BigNat::from(190569292usize));
+    assert_eq!(
+        partition_count(1000),
+        nat("24061467864032622473692149727991")
+    );
+}
+
+#[test]
+fn sequences_bernoulli() {
+    let rational = |s: &str| BigRational::try_from(s).unwrap();
+    assert_eq!(bernoulli(0), rational("1"));
+    assert_eq!(bernoulli(1), rational("-1/2"));
+    assert_eq!(bernoulli(2), rational("1/6"));
+    assert_eq!(bernoulli(3), rational("0"));
+    assert_eq!(bernoulli(4), rational("-1/30"));
+    assert_eq!(bernoulli(40), rational("-261082718496449122051/13530"));
+    assert_eq!(
+        bernoulli(60),
+        rational("-1215233140483755572040304994079820246041491/56786730")
+    );
+}
@@ -0,0 +1,22 @@
+use apnum::constants::{e, pi};
+use apnum::spigot::{e_digits, pi_digits};
+
+#[test]
+fn spigot_digits() {
+    let digits: Vec<u8> = pi_digits().take(12).collect();
+    assert_eq!(digits, [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8]);
+    let digits: Vec<u8> = e_digits().take(12).collect();
+    assert_eq!(digits, [2, 7, 1, 8, 2, 8, 1, 8, 2, 8, 4, 5]);
+
+    // The streamed digits agree with the ones computed at a fixed precision
+    let streamed: String = pi_digits()
+        .take(501)
+        .map(|digit| char::from(b'0' + digit))
+        .collect();
+    assert_eq!(streamed, pi(500).to_string());
+    let streamed: String = e_digits()
+        .take(501)
+        .map(|digit| char::from(b'0' + digit))
+        .collect();
+    assert_eq!(streamed, e(500).to_string());
+}
@@ -0,0 +1,46 @@
+use apnum::symbol::{jacobi, kronecker, legendre};
+use apnum::{BigInt, BigNat};
+
+#[test]
+fn symbol_jacobi() {
+    let n = BigNat::from(45usize);
+    let symbols: Vec<i8> = (-5..=5).map(|a| jacobi(&BigInt::from(a), &n)).collect();
+    assert_eq!(symbols, [0, 1, 0, -1, 1, 0, 1, -1, 0, 1, 0]);
+
+    assert_eq!(jacobi(&BigInt::from(7), &BigNat::from(1usize)), 1);
+
+    let a = BigInt::from(10).pow(30) + 3;
+    let n = BigNat::from(10usize).pow(31) + 1u8;
+    assert_eq!(jacobi(&a, &n), -1);
+    assert_eq!(jacobi(&-a, &n), -1);
+}
+
+#[test]
+fn symbol_legendre() {
+    let p = BigNat::from(10usize).pow(30) + 57u8;
+    assert_eq!(legendre(&BigInt::from(2), &p), 1);
+    assert_eq!(legendre(&BigInt::from(3), &p), 1);
+    assert_eq!(legendre(&BigInt::from(&p + 3u8), &p), 1);
+    assert_eq!(legendre(&BigInt::from(&p * 2u8), &p), 0);
+    assert_eq!(legendre(&BigInt::from(-1), &BigNat::from(7usize)), -1);
+}
+
+#[test]
+fn symbol_kronecker() {
+    let pairs = [
+        (3, 8, -1),
+        (5, 8, -1),
+        (-3, 8, -1),
+        (7, -8, 1),
+        (-7, -8, -1),
+        (6, 4, 0),
+        (1, 0, 1),
+        (-1, 0, 1),
+        (2, 0, 0),
+        (-5, 12, 1),
+        (5, -12, -1),
+    ];
+    for (a, n, symbol) in pairs {
+        assert_eq!(kronecker(&BigInt::from(a), &BigInt::from(n)), symbol);
+    }
+}
@@ -0,0 +1,131 @@
+use apnum::{APNum, BigNat, Uint, U256, U512};
+
+fn xorshift(seed: &mut u64) -> u64 {
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+    *seed
+}
+
+/// Random values with runs of zero and full digits
+fn random<const LIMBS: usize>(seed: &mut u64) -> Uint<LIMBS> {
+    let length = xorshift(seed) as usize % (LIMBS + 1);
+    let mut limbs = [0; LIMBS];
+    for limb in limbs.iter_mut().take(length) {
+        *limb = match xorshift(seed) % 4 {
+            0 => 0,
+            1 => u32::MAX,
+            _ => xorshift(seed) as u32,
+        };
+    }
+    Uint::from_limbs(limbs)
+}
+
+fn check_against_bignat<const LIMBS: usize>(seed: &mut u64) {
+    let modulus = BigNat::from(1usize) << Uint::<LIMBS>::BITS;
+    let reduce = |x: BigNat| Uint::<LIMBS>::try_from(&(&x / &modulus).1).unwrap();
+    for _ in 0..200 {
+        let (a, b) = (random::<LIMBS>(seed), random::<LIMBS>(seed));
+        let (x, y) = (BigNat::from(a), BigNat::from(b));
+        let sum = &x + &y;
+        assert_eq!(a.overflowing_add(&b), (reduce(sum.clone()), sum >= modulus));
+        let product = &x * &y;
+        assert_eq!(
+            a.overflowing_mul(&b),
+            (reduce(product.clone()), product >= modulus)
+        );
+        assert_eq!(
+            a.overflowing_sub(&b),
+            (
+                reduce(BigNat::try_from(&(&x + &modulus) - &y).unwrap()),
+                x < y
+            )
+        );
+        assert_eq!(a.cmp(&b), x.cmp(&y));
+        if !y.is_zero() {
+            let (q, r) = &x / &y;
+            assert_eq!(a / b, (reduce(q), reduce(r)));
+        }
+        let shift = xorshift(seed) as usize % (Uint::<LIMBS>::BITS + 8);
+        assert_eq!(a << shift, reduce(&x << shift));
+        assert_eq!(a >> shift, reduce(&x >> shift));
+    }
+}
+
+#[test]
+fn uint_against_bignat() {
+    let mut seed = 0x9e3779b97f4a7c15;
+    check_against_bignat::<1>(&mut seed);
+    check_against_bignat::<3>(&mut seed);
+    check_against_bignat::<8>(&mut seed);
+    check_against_bignat::<16>(&mut seed);
+}
+
+#[test]
+fn uint_wrapping() {
+    let one = U256::from(1u32);
+    assert_eq!(U256::MAX.wrapping_add(&one), U256::ZERO);
+    assert_eq!(U256::MAX.checked_add(&one), None);
+    assert_eq!(U256::ZERO.wrapping_sub(&one), U256::MAX);
+    assert_eq!(U256::ZERO.checked_sub(&one), None);
+    assert_eq!(one.wrapping_neg(), U256::MAX);
+    assert_eq!(U256::MAX * U256::MAX, one);
+    assert_eq!(U256::MAX.checked_mul(&U256::MAX), None);
+    assert_eq!(
+        U256::from(3u32).checked_mul(&U256::from(5u32)),
+        Some(U256::from(15u32))
+    );
+
+    // 2^255 * 2 wraps to zero, 3^161 > 2^256
+    let top = one << 255;
+    assert_eq!(top.overflowing_mul(&U256::from(2u32)), (U256::ZERO, true));
+    let power = U256::from(3u32).wrapping_pow(161);
+    let expected = (&BigNat::from(3usize).pow(161) / &(BigNat::from(1usize) << 256)).1;
+    assert_eq!(BigNat::from(power), expected);
+
+    assert_eq!(U256::BITS, 256);
+    assert_eq!(U512::BITS, 512);
+    assert_eq!(top.bits(), 256);
+    assert_eq!(top.leading_zeros(), 0);
+    assert_eq!(U256::ZERO.bits(), 0);
+    assert!(top.bit(255) && !top.bit(254));
+    assert_eq!(!U256::ZERO, U256::MAX);
+    assert_eq!(
+        U256::from(0b1100u32) & U256::from(0b1010u32),
+        U256::from(0b1000u32)
+    );
+    assert_eq!(
+        U256::from(0b1100u32) ^ U256::from(0b1010u32),
+        U256::from(0b0110u32)
+    );
+    assert_eq!(
+        U256::from(0b1100u32) | U256::from(0b1010u32),
+        U256::from(0b1110u32)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Division by Zero")]
+fn uint_div_by_zero() {
+    let _ = U256::from(1u32) / U256::ZERO;
+}
+
+#[test]
+fn uint_conversions() {
+    let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+    assert_eq!(U256::MAX.to_string(), max);
+    assert_eq!(max.parse::<U256>().unwrap(), U256::MAX);
+    assert!(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+            .parse::<U256>()
+            .is_err()
+    );
+    assert_eq!(U256::ZERO.to_string(), "0");
+
+    let x = BigNat::from(u64::MAX) * BigNat::from(12345678901234567usize);
+    let y = U512::try_from(&x).unwrap();
+    assert_eq!(BigNat::from(y), x);
+    assert!(Uint::<2>::try_from(&x).is_err());
+    assert_eq!(U256::from(u64::MAX).limbs()[..3], [u32::MAX, u32::MAX, 0]);
+    assert_eq!(Uint::<1>::from(u64::MAX), Uint::<1>::MAX);
+}